@@ -8,7 +8,9 @@ use futures::{Future, FutureExt};
 use tokio::time::timeout;
 use tracing_futures::Instrument;
 
-use telegram_bot_raw::{HttpRequest, Request, ResponseType};
+use telegram_bot_raw::{
+    HttpRequest, Message, MessageOrChannelPost, Request, ResponseType, SendMessage, SplitCaption,
+};
 
 use crate::connector::{default_connector, Connector};
 use crate::errors::{Error, ErrorKind};
@@ -170,6 +172,101 @@ impl Api {
         }
     }
 
+    /// Send a text message, splitting it into several messages if it is too long.
+    /// Messages are sent one after another, see `SendMessage::split` for details. Nothing is sent
+    /// if the markup of the text can't be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, ChatId, FormattedText, prelude::*};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let chat = ChatId::new(61031);
+    /// let text = FormattedText::from("Long log output...\n".repeat(1000));
+    /// let result = api.send_split(chat.text("").formatted_text(text).clone()).await;
+    /// println!("{:?}", result);
+    /// # }
+    /// # }
+    /// ```
+    pub fn send_split(
+        &self,
+        request: SendMessage<'_>,
+    ) -> impl Future<Output = Result<Vec<MessageOrChannelPost>, Error>> + Send {
+        let api = self.clone();
+        let requests = request
+            .split()
+            .map(|requests| requests.iter().map(Request::serialize).collect::<Vec<_>>());
+        async move {
+            let requests = requests.map_err(ErrorKind::from)?;
+            let mut messages = Vec::with_capacity(requests.len());
+            for request in requests {
+                let request = request.map_err(ErrorKind::from)?;
+                let message = api
+                    .send_http_request::<<SendMessage as Request>::Response>(request)
+                    .await?;
+                messages.push(message);
+            }
+            Ok(messages)
+        }
+    }
+
+    /// Send a media message, sending the rest of a too long caption as text messages after it.
+    /// See `SplitCaption::split_caption` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, ChatId, FileRef, FormattedText, prelude::*};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let chat = ChatId::new(61031);
+    /// let caption = FormattedText::from("Long description...\n".repeat(100));
+    /// let photo = chat.photo(FileRef::from("AgADBAADtqcxG")).formatted_caption(caption).clone();
+    /// let result = api.send_split_caption(photo).await;
+    /// println!("{:?}", result);
+    /// # }
+    /// # }
+    /// ```
+    pub fn send_split_caption<'c, Req>(
+        &self,
+        request: Req,
+    ) -> impl Future<Output = Result<Vec<MessageOrChannelPost>, Error>> + Send
+    where
+        Req: Request + SplitCaption<'c>,
+        Req::Response: ResponseType<Type = Message>,
+    {
+        let api = self.clone();
+        let requests = request.split_caption().map(|(media, rest)| {
+            let rest = rest.iter().map(Request::serialize).collect::<Vec<_>>();
+            (media.serialize(), rest)
+        });
+        async move {
+            let (media, rest) = requests.map_err(ErrorKind::from)?;
+            let mut messages = Vec::with_capacity(rest.len() + 1);
+            let message = api
+                .send_http_request::<Req::Response>(media.map_err(ErrorKind::from)?)
+                .await?;
+            messages.push(MessageOrChannelPost::Message(message));
+            for request in rest {
+                let request = request.map_err(ErrorKind::from)?;
+                let message = api
+                    .send_http_request::<<SendMessage as Request>::Response>(request)
+                    .await?;
+                messages.push(message);
+            }
+            Ok(messages)
+        }
+    }
+
     async fn send_http_request<Resp: ResponseType>(
         &self,
         request: HttpRequest,
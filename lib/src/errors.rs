@@ -7,10 +7,12 @@ pub struct Error(ErrorKind);
 #[derive(Debug)]
 pub(crate) enum ErrorKind {
     Raw(telegram_bot_raw::Error),
+    Markup(telegram_bot_raw::MarkupError),
     Hyper(hyper::Error),
     Http(hyper::http::Error),
     Io(std::io::Error),
     InvalidMultipartFilename,
+    MissingBotUsername,
 }
 
 impl From<telegram_bot_raw::Error> for ErrorKind {
@@ -19,6 +21,12 @@ impl From<telegram_bot_raw::Error> for ErrorKind {
     }
 }
 
+impl From<telegram_bot_raw::MarkupError> for ErrorKind {
+    fn from(error: telegram_bot_raw::MarkupError) -> Self {
+        ErrorKind::Markup(error)
+    }
+}
+
 impl From<hyper::Error> for ErrorKind {
     fn from(error: hyper::Error) -> Self {
         ErrorKind::Hyper(error)
@@ -47,10 +55,12 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.0 {
             ErrorKind::Raw(error) => write!(f, "{}", error),
+            ErrorKind::Markup(error) => write!(f, "{}", error),
             ErrorKind::Hyper(error) => write!(f, "{}", error),
             ErrorKind::Http(error) => write!(f, "{}", error),
             ErrorKind::Io(error) => write!(f, "{}", error),
             ErrorKind::InvalidMultipartFilename => write!(f, "invalid multipart filename"),
+            ErrorKind::MissingBotUsername => write!(f, "bot has no username"),
         }
     }
 }
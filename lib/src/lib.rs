@@ -15,4 +15,6 @@ pub use self::api::Api;
 pub use self::errors::Error;
 pub use prelude::*;
 pub use stream::UpdatesStream;
+pub use telegram_bot_raw::formatting;
+pub use telegram_bot_raw::formatting::FormattedText;
 pub use types::*;
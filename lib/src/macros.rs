@@ -5,9 +5,12 @@
 /// # #[macro_use] extern crate telegram_bot;
 /// # fn main() {
 /// let reply_keyboard = reply_markup!(reply_keyboard, selective, one_time, resize,
+///     placeholder "Choose an option",
 ///     ["button", "button"],
 ///     ["button" contact], // Request contact
-///     ["button" location] // Request location
+///     ["button" location], // Request location
+///     ["button" poll, "button" quiz, "button" regular], // Request poll
+///     ["button" web_app "https://example.com/app"] // Launch web app
 /// );
 ///
 /// let remove_keyboard = reply_markup!(remove_keyboard);
@@ -15,12 +18,17 @@
 ///
 /// let force_reply = reply_markup!(force_reply);
 /// let selective_force_reply = reply_markup!(force_reply, selective);
+/// let placeholder_force_reply = reply_markup!(force_reply, selective, placeholder "Your name");
 ///
 /// let inline_keyboard = reply_markup!(inline_keyboard,
 ///     ["button" callback "0,0", "button" callback "0,1"],
-///     ["button" callback "1,0", "button" callback "1,1", "button" callback "1,2"]
+///     ["button" callback "1,0", "button" callback "1,1", "button" callback "1,2"],
+///     ["button" url "https://example.com", "button" login_url "https://example.com/login"],
+///     ["button" switch_inline_query "query", "button" switch_inline_query_current_chat ""],
+///     ["button" web_app "https://example.com/app", "button" pay, "button" callback_game]
 /// );
 ///
+/// # drop(placeholder_force_reply);
 /// # drop(inline_keyboard);
 /// # drop(selective_force_reply);
 /// # drop(force_reply);
@@ -51,6 +59,18 @@ macro_rules! reply_markup {
           keyboard
     });
 
+    (force_reply, placeholder $placeholder:expr) => ({
+          let mut keyboard = reply_markup!(force_reply);
+          keyboard.input_field_placeholder($placeholder);
+          keyboard
+    });
+
+    (force_reply, selective, placeholder $placeholder:expr) => ({
+          let mut keyboard = reply_markup!(force_reply, selective);
+          keyboard.input_field_placeholder($placeholder);
+          keyboard
+    });
+
     (reply_keyboard, $($content:tt)*) => ({
         reply_markup!(_reply_keyboard, $($content)*)
     });
@@ -73,6 +93,12 @@ macro_rules! reply_markup {
         keyboard
     });
 
+    (_reply_keyboard, placeholder $placeholder:expr, $($content:tt)*) => ({
+        let mut keyboard = reply_markup!(_reply_keyboard, $($content)*);
+        keyboard.input_field_placeholder($placeholder);
+        keyboard
+    });
+
     (_reply_keyboard, $([$($content:tt)*]), *) => (
         $crate::ReplyKeyboardMarkup::from(vec![$(reply_markup![_reply_keyboard_row, $($content)*]), *])
     );
@@ -88,6 +114,13 @@ macro_rules! reply_markup {
         reply_markup!(_reply_keyboard_row, ($($acc)* reply_markup!(_reply_keyboard_button, $value, $request),); $($remaining)*)
     );
 
+    (_reply_keyboard_row, ($($acc:tt)*); $value:tt $request:tt $argument:tt) => (
+        vec![$($acc)* reply_markup!(_reply_keyboard_button, $value, $request, $argument)]
+    );
+    (_reply_keyboard_row, ($($acc:tt)*); $value:tt $request:tt $argument:tt, $($remaining: tt)*) => (
+        reply_markup!(_reply_keyboard_row, ($($acc)* reply_markup!(_reply_keyboard_button, $value, $request, $argument),); $($remaining)*)
+    );
+
     (_reply_keyboard_row, $($content:expr), *) => (vec![$(reply_markup!(_reply_keyboard_button,  $content)), *]);
     (_reply_keyboard_row, $($content:tt)*) => (reply_markup!(_reply_keyboard_row, (); $($content)*));
 
@@ -102,17 +135,47 @@ macro_rules! reply_markup {
         button.request_location();
         button
     });
+    (_reply_keyboard_button, $value:expr, poll) => ({
+        let mut button: $crate::KeyboardButton = reply_markup!(_reply_keyboard_button, $value);
+        button.request_poll(None);
+        button
+    });
+
+    (_reply_keyboard_button, $value:expr, quiz) => ({
+        let mut button: $crate::KeyboardButton = reply_markup!(_reply_keyboard_button, $value);
+        button.request_poll(Some($crate::PollType::Quiz));
+        button
+    });
+
+    (_reply_keyboard_button, $value:expr, regular) => ({
+        let mut button: $crate::KeyboardButton = reply_markup!(_reply_keyboard_button, $value);
+        button.request_poll(Some($crate::PollType::Regular));
+        button
+    });
+
+    (_reply_keyboard_button, $value:expr, web_app, $url:expr) => ({
+        let mut button: $crate::KeyboardButton = reply_markup!(_reply_keyboard_button, $value);
+        button.web_app($url);
+        button
+    });
     (_reply_keyboard_button, $value:expr) => ($value.into());
 
     (inline_keyboard, $([$($content:tt)*]), *) => (
         $crate::InlineKeyboardMarkup::from(vec![$(reply_markup![_inline_keyboard_row, $($content)*]), *])
     );
 
-    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt $callback:tt) => (
-        vec![$($acc)* reply_markup!(_inline_keyboard_button, $request,  $text, $callback)]
+    (_inline_keyboard_row, ($($acc:tt)*);) => (vec![$($acc)*]);
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:ident) => (
+        vec![$($acc)* reply_markup!(_inline_keyboard_button, $request, $text)]
+    );
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:ident, $($remaining:tt)*) => (
+        reply_markup!(_inline_keyboard_row, ($($acc)* reply_markup!(_inline_keyboard_button, $request, $text),); $($remaining)*)
+    );
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:ident $value:tt) => (
+        vec![$($acc)* reply_markup!(_inline_keyboard_button, $request, $text, $value)]
     );
-    (_inline_keyboard_row, $($text:tt $request:tt $callback:tt), *) => (
-        vec![$(reply_markup!(_inline_keyboard_button, $request, $text, $callback)), *]
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:ident $value:tt, $($remaining:tt)*) => (
+        reply_markup!(_inline_keyboard_row, ($($acc)* reply_markup!(_inline_keyboard_button, $request, $text, $value),); $($remaining)*)
     );
     (_inline_keyboard_row, $($content:tt)*) => (reply_markup!(_inline_keyboard_row, (); $($content)*));
 
@@ -122,6 +185,24 @@ macro_rules! reply_markup {
     (_inline_keyboard_button, url, $text:expr, $url:expr) => (
         $crate::InlineKeyboardButton::url($text, $url)
     );
+    (_inline_keyboard_button, switch_inline_query, $text:expr, $query:expr) => (
+        $crate::InlineKeyboardButton::switch_inline_query($text, $query)
+    );
+    (_inline_keyboard_button, switch_inline_query_current_chat, $text:expr, $query:expr) => (
+        $crate::InlineKeyboardButton::switch_inline_query_current_chat($text, $query)
+    );
+    (_inline_keyboard_button, login_url, $text:expr, $url:expr) => (
+        $crate::InlineKeyboardButton::login_url($text, $url)
+    );
+    (_inline_keyboard_button, web_app, $text:expr, $url:expr) => (
+        $crate::InlineKeyboardButton::web_app($text, $url)
+    );
+    (_inline_keyboard_button, pay, $text:expr) => (
+        $crate::InlineKeyboardButton::pay($text)
+    );
+    (_inline_keyboard_button, callback_game, $text:expr) => (
+        $crate::InlineKeyboardButton::callback_game($text)
+    );
 }
 
 #[cfg(test)]
@@ -141,6 +222,12 @@ mod tests {
 
         force_reply.selective();
         assert_eq!(force_reply, reply_markup!(force_reply, selective));
+
+        force_reply.input_field_placeholder("name");
+        assert_eq!(
+            force_reply,
+            reply_markup!(force_reply, selective, placeholder "name")
+        );
     }
 
     #[test]
@@ -228,6 +315,50 @@ mod tests {
                 ["spam"]
             )
         );
+
+        {
+            let row = keyboard.add_empty_row();
+
+            let mut poll_button = KeyboardButton::new("poll");
+            poll_button.request_poll(None);
+            row.push(poll_button);
+
+            let mut quiz_button = KeyboardButton::new("quiz");
+            quiz_button.request_poll(Some(PollType::Quiz));
+            row.push(quiz_button);
+
+            let mut web_app_button = KeyboardButton::new("app");
+            web_app_button.web_app("https://example.com");
+            row.push(web_app_button);
+        }
+        keyboard.input_field_placeholder("choose");
+        assert_eq!(
+            keyboard,
+            reply_markup!(
+                reply_keyboard, resize, selective, one_time, placeholder "choose",
+                [], ["foo", "bar"], ["baz"],
+                ["contact" contact, "location" location],
+                ["spam"],
+                ["poll" poll, "quiz" quiz, "app" web_app "https://example.com"]
+            )
+        );
+    }
+
+    #[test]
+    fn test_reply_keyboard_placeholder_expr() {
+        let mut keyboard = ReplyKeyboardMarkup::new();
+        keyboard.add_row(vec![KeyboardButton::new("yes")]);
+        keyboard.input_field_placeholder("Choose 1 of 1");
+
+        let count = 1;
+        assert_eq!(
+            keyboard,
+            reply_markup!(
+                reply_keyboard,
+                placeholder format!("Choose {} of {}", count, count),
+                ["yes"]
+            )
+        );
     }
 
     #[test]
@@ -247,5 +378,38 @@ mod tests {
             markup,
             reply_markup!(inline_keyboard, [], ["foo" callback "bar", "baz" callback "quux"])
         );
+
+        {
+            let row = markup.add_empty_row();
+            row.push(InlineKeyboardButton::pay("pay"));
+            row.push(InlineKeyboardButton::url("url", "https://example.com"));
+            row.push(InlineKeyboardButton::callback_game("game"));
+        }
+        {
+            let row = markup.add_empty_row();
+            row.push(InlineKeyboardButton::switch_inline_query("inline", "q"));
+            row.push(InlineKeyboardButton::switch_inline_query_current_chat(
+                "here", "",
+            ));
+            row.push(InlineKeyboardButton::login_url(
+                "login",
+                "https://example.com/login",
+            ));
+            row.push(InlineKeyboardButton::web_app("app", "https://example.com"));
+        }
+        assert_eq!(
+            markup,
+            reply_markup!(inline_keyboard,
+                [],
+                ["foo" callback "bar", "baz" callback "quux"],
+                ["pay" pay, "url" url "https://example.com", "game" callback_game],
+                [
+                    "inline" switch_inline_query "q",
+                    "here" switch_inline_query_current_chat "",
+                    "login" login_url "https://example.com/login",
+                    "app" web_app "https://example.com"
+                ]
+            )
+        );
     }
 }
@@ -4,19 +4,36 @@
 
 pub use telegram_bot_raw::CanAnswerCallbackQuery;
 pub use telegram_bot_raw::CanAnswerInlineQuery;
-pub use telegram_bot_raw::CanExportChatInviteLink;
 pub use telegram_bot_raw::CanLeaveChat;
 pub use telegram_bot_raw::CanSendChatAction;
-pub use telegram_bot_raw::{CanDeleteMessage, CanForwardMessage};
+pub use telegram_bot_raw::CanSetChatPermissions;
+pub use telegram_bot_raw::CanSetPassportDataErrors;
+pub use telegram_bot_raw::{CanAnswerPreCheckoutQuery, CanAnswerShippingQuery, CanSendInvoice};
+pub use telegram_bot_raw::{
+    CanApproveChatJoinRequestForChat, CanApproveChatJoinRequestForUser,
+    CanDeclineChatJoinRequestForChat, CanDeclineChatJoinRequestForUser,
+};
+pub use telegram_bot_raw::{CanBanChatSenderChatForChat, CanBanChatSenderChatForSenderChat};
+pub use telegram_bot_raw::{CanCopyMessage, CanDeleteMessage, CanForwardMessage};
+pub use telegram_bot_raw::{
+    CanCreateChatInviteLink, CanEditChatInviteLink, CanExportChatInviteLink,
+    CanRevokeChatInviteLink,
+};
+pub use telegram_bot_raw::{CanDeleteChatPhoto, CanSetChatPhoto};
+pub use telegram_bot_raw::{CanDeleteChatStickerSet, CanSetChatStickerSet};
 pub use telegram_bot_raw::{CanEditMessageCaption, CanEditMessageReplyMarkup, CanEditMessageText};
 pub use telegram_bot_raw::{CanEditMessageLiveLocation, CanStopMessageLiveLocation};
 pub use telegram_bot_raw::{CanGetChat, CanGetChatAdministrators, CanGetChatMembersCount};
 pub use telegram_bot_raw::{CanGetChatMemberForChat, CanGetChatMemberForUser};
+pub use telegram_bot_raw::{CanGetChatMenuButton, CanSetChatMenuButton};
 pub use telegram_bot_raw::{CanGetFile, CanGetUserProfilePhotos};
+pub use telegram_bot_raw::{CanGetGameHighScores, CanReplySendGame, CanSendGame, CanSetGameScore};
 pub use telegram_bot_raw::{CanKickChatMemberForChat, CanKickChatMemberForUser};
-pub use telegram_bot_raw::{CanPinMessage, CanUnpinMessage};
+pub use telegram_bot_raw::{CanPinMessage, CanUnpinAllChatMessages, CanUnpinMessage};
+pub use telegram_bot_raw::{CanPromoteChatMemberForChat, CanPromoteChatMemberForUser};
 pub use telegram_bot_raw::{CanReplySendAudio, CanSendAudio};
 pub use telegram_bot_raw::{CanReplySendContact, CanSendContact};
+pub use telegram_bot_raw::{CanReplySendDice, CanSendDice};
 pub use telegram_bot_raw::{CanReplySendDocument, CanSendDocument};
 pub use telegram_bot_raw::{CanReplySendLocation, CanSendLocation};
 pub use telegram_bot_raw::{CanReplySendMessage, CanSendMessage};
@@ -24,6 +41,11 @@ pub use telegram_bot_raw::{CanReplySendPhoto, CanSendPhoto};
 pub use telegram_bot_raw::{CanReplySendPoll, CanSendPoll, CanStopPoll};
 pub use telegram_bot_raw::{CanReplySendVenue, CanSendVenue};
 pub use telegram_bot_raw::{CanReplySendVideo, CanSendVideo};
+pub use telegram_bot_raw::{CanRestrictChatMemberForChat, CanRestrictChatMemberForUser};
+pub use telegram_bot_raw::{
+    CanSetChatAdministratorCustomTitleForChat, CanSetChatAdministratorCustomTitleForUser,
+};
+pub use telegram_bot_raw::{CanSetChatDescription, CanSetChatTitle};
 pub use telegram_bot_raw::{CanUnbanChatMemberForChat, CanUnbanChatMemberForUser};
 pub use telegram_bot_raw::{ToReplyRequest, ToRequest};
 
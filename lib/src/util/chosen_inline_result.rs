@@ -0,0 +1,196 @@
+//! Resolving chosen inline results back to the results served to the user.
+//!
+//! `ChosenInlineResult` updates carry only the `result_id` of the picked result.
+//! [`InlineResultStore`] remembers results sent with `AnswerInlineQuery` for as long as Telegram
+//! may serve them from its cache plus a configurable window, and resolves a `ChosenInlineResult`
+//! to the original `InlineQueryResult` and query.
+//!
+//! [`InlineResultStore`]: struct.InlineResultStore.html
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use crate::types::{AnswerInlineQuery, ChosenInlineResult, InlineQuery, InlineQueryResult, UserId};
+use crate::util::inline_query::DEFAULT_CACHE_TIME;
+
+/// Default time results are remembered for after Telegram stops serving them from its cache.
+pub const DEFAULT_RESULT_WINDOW: Duration = Duration::from_secs(600);
+
+/// Result served in an answer to an inline query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServedResult {
+    /// Query the result was served for. Answers which are not personal are cached by Telegram
+    /// for all users, so this is the last query they were recorded for, possibly of another user.
+    pub query: InlineQuery,
+    pub result: InlineQueryResult,
+    /// Time after which the result is forgotten.
+    pub expires: SystemTime,
+}
+
+/// Key of a served result.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServedResultKey {
+    /// User who sent the query, `None` if the answer was not personal.
+    pub user: Option<UserId>,
+    /// Text of the query.
+    pub query: String,
+    pub result_id: String,
+}
+
+/// Storage of served results.
+///
+/// Implement it to keep served results outside of the process, e.g. to share them between
+/// several instances of the bot.
+pub trait InlineResultStorage {
+    /// Remember a result, replacing the result previously served with the same key.
+    fn insert(&self, key: ServedResultKey, served: ServedResult);
+
+    fn get(&self, key: &ServedResultKey) -> Option<ServedResult>;
+
+    /// Forget results which expire before `now`.
+    fn remove_expired(&self, now: SystemTime);
+}
+
+/// In-process storage of served results.
+#[derive(Debug, Default)]
+pub struct MemoryInlineResultStorage {
+    results: Mutex<HashMap<ServedResultKey, ServedResult>>,
+}
+
+impl MemoryInlineResultStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl InlineResultStorage for MemoryInlineResultStorage {
+    fn insert(&self, key: ServedResultKey, served: ServedResult) {
+        self.results.lock().unwrap().insert(key, served);
+    }
+
+    fn get(&self, key: &ServedResultKey) -> Option<ServedResult> {
+        self.results.lock().unwrap().get(key).cloned()
+    }
+
+    fn remove_expired(&self, now: SystemTime) {
+        let mut results = self.results.lock().unwrap();
+        results.retain(|_, served| served.expires > now);
+    }
+}
+
+/// Correlates chosen inline results with the results served to the user.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::{Api, InlineQuery, UpdateKind};
+/// # use telegram_bot::prelude::*;
+/// # use telegram_bot::util::chosen_inline_result::InlineResultStore;
+/// # async fn handle(api: Api, kind: UpdateKind) -> Result<(), telegram_bot::Error> {
+/// let store = InlineResultStore::new();
+///
+/// match kind {
+///     UpdateKind::InlineQuery(query) => {
+///         let answer = query.id.clone().answer(vec![]);
+///         store.record(&query, &answer);
+///         api.send(answer).await?;
+///     }
+///     UpdateKind::ChosenInlineResult(chosen) => {
+///         if let Some(served) = store.resolve(&chosen) {
+///             println!("{} picked {:?}", served.query.query, served.result);
+///         }
+///     }
+///     _ => (),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct InlineResultStore<S = MemoryInlineResultStorage> {
+    storage: S,
+    window: Duration,
+}
+
+impl InlineResultStore {
+    /// Create a store keeping results in-process.
+    pub fn new() -> Self {
+        Self::with_storage(MemoryInlineResultStorage::new())
+    }
+}
+
+impl Default for InlineResultStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: InlineResultStorage> InlineResultStore<S> {
+    pub fn with_storage(storage: S) -> Self {
+        InlineResultStore {
+            storage,
+            window: DEFAULT_RESULT_WINDOW,
+        }
+    }
+
+    /// Time results are remembered for after Telegram stops serving them from its cache,
+    /// i.e. after `cache_time` of the answer has passed.
+    pub fn window(&mut self, window: Duration) -> &mut Self {
+        self.window = window;
+        self
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Remember results of `answer` served for `query`.
+    pub fn record(&self, query: &InlineQuery, answer: &AnswerInlineQuery) {
+        self.record_at(query, answer, SystemTime::now())
+    }
+
+    /// Remember results of `answer` served for `query` at `now`.
+    pub fn record_at(&self, query: &InlineQuery, answer: &AnswerInlineQuery, now: SystemTime) {
+        self.storage.remove_expired(now);
+        let cache_time = answer.cached_for().map_or(DEFAULT_CACHE_TIME, |secs| {
+            Duration::from_secs(secs.max(0) as u64)
+        });
+        let user = if answer.personal() {
+            Some(query.from.id)
+        } else {
+            None
+        };
+
+        for result in answer.results() {
+            let served = ServedResult {
+                query: query.clone(),
+                result: result.clone(),
+                expires: now + cache_time + self.window,
+            };
+            let key = ServedResultKey {
+                user,
+                query: query.query.clone(),
+                result_id: result.id().to_owned(),
+            };
+            self.storage.insert(key, served);
+        }
+    }
+
+    /// Find the result the user has chosen.
+    pub fn resolve(&self, chosen: &ChosenInlineResult) -> Option<ServedResult> {
+        self.resolve_at(chosen, SystemTime::now())
+    }
+
+    /// Find the result the user has chosen, considering results expired at `now` forgotten.
+    /// Results of personal answers are preferred to results served to all users.
+    pub fn resolve_at(&self, chosen: &ChosenInlineResult, now: SystemTime) -> Option<ServedResult> {
+        [Some(chosen.from.id), None].iter().find_map(|&user| {
+            let key = ServedResultKey {
+                user,
+                query: chosen.query.clone(),
+                result_id: chosen.result_id.clone(),
+            };
+            self.storage.get(&key).filter(|served| served.expires > now)
+        })
+    }
+}
@@ -0,0 +1,202 @@
+//! Deep links starting the bot with a payload.
+//!
+//! Links `https://t.me/<bot>?start=<payload>` open a private chat with the bot and
+//! `https://t.me/<bot>?startgroup=<payload>` add the bot to a group, after which the bot
+//! receives a `/start <payload>` message. Payloads are 1-64 characters from the base64url
+//! alphabet, typed payloads are encoded in the compact binary form of callback data
+//! converted to base64url.
+
+use std::error;
+use std::fmt;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::ErrorKind;
+use crate::types::{decode_compact, encode_compact, GetMe, Message, MessageKind};
+use crate::{Api, Error};
+
+/// Maximum length of a deep link payload.
+pub const MAX_PAYLOAD_LENGTH: usize = 64;
+
+/// Builds deep links to the bot.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::util::deep_link::DeepLinks;
+/// let links = DeepLinks::new("example_bot");
+/// assert_eq!(
+///     links.start_raw("ref-42").unwrap(),
+///     "https://t.me/example_bot?start=ref-42"
+/// );
+/// assert_eq!(
+///     links.start(&("ref", 42u32)).unwrap(),
+///     "https://t.me/example_bot?start=A3JlZio"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeepLinks {
+    username: String,
+}
+
+/// Error of deep link payload encoding or decoding.
+#[derive(Debug)]
+pub enum DeepLinkError {
+    /// Payload is empty.
+    Empty,
+    /// Payload is longer than `MAX_PAYLOAD_LENGTH` characters.
+    TooLong(usize),
+    /// Payload contains characters other than `A-Z`, `a-z`, `0-9`, `_` and `-`.
+    InvalidCharacters,
+    /// Payload is not valid base64url.
+    InvalidBase64,
+    /// Payload can't be encoded or doesn't match the expected type.
+    /// Be aware that anyone can create a link with an arbitrary payload.
+    Malformed(bincode::Error),
+}
+
+impl DeepLinks {
+    /// Links to the bot with `username`, with or without the leading `@`.
+    pub fn new<S: AsRef<str>>(username: S) -> Self {
+        DeepLinks {
+            username: username.as_ref().trim_start_matches('@').to_owned(),
+        }
+    }
+
+    /// Links to the bot with the username obtained with `GetMe`.
+    pub async fn from_api(api: &Api) -> Result<Self, Error> {
+        let me = api.send(GetMe).await?;
+        match me.username {
+            Some(username) => Ok(DeepLinks::new(username)),
+            None => Err(ErrorKind::MissingBotUsername.into()),
+        }
+    }
+
+    /// Username of the bot.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Link opening a private chat with the bot with typed `payload`.
+    pub fn start<T: Serialize>(&self, payload: &T) -> Result<String, DeepLinkError> {
+        self.start_raw(&encode_payload(payload)?)
+    }
+
+    /// Link adding the bot to a group with typed `payload`.
+    pub fn start_group<T: Serialize>(&self, payload: &T) -> Result<String, DeepLinkError> {
+        self.start_group_raw(&encode_payload(payload)?)
+    }
+
+    /// Link opening a private chat with the bot with `payload` as is.
+    pub fn start_raw(&self, payload: &str) -> Result<String, DeepLinkError> {
+        self.link("start", payload)
+    }
+
+    /// Link adding the bot to a group with `payload` as is.
+    pub fn start_group_raw(&self, payload: &str) -> Result<String, DeepLinkError> {
+        self.link("startgroup", payload)
+    }
+
+    /// Payload of a `/start <payload>` message, `None` if the message is not a `/start`
+    /// command of this bot or has no payload. Commands addressed to the bot as
+    /// `/start@<username>` are matched case-insensitively, other bots' commands are ignored.
+    pub fn start_payload<'m>(&self, message: &'m Message) -> Option<&'m str> {
+        let text = match message.kind {
+            MessageKind::Text { ref data, .. } => data,
+            _ => return None,
+        };
+
+        let mut parts = text.splitn(2, ' ');
+        let mut command = parts.next()?.splitn(2, '@');
+        if command.next()? != "/start" {
+            return None;
+        }
+        if let Some(username) = command.next() {
+            if !username.eq_ignore_ascii_case(&self.username) {
+                return None;
+            }
+        }
+        parts
+            .next()
+            .map(str::trim)
+            .filter(|payload| !payload.is_empty())
+    }
+
+    /// Decode typed payload of a `/start <payload>` message, `None` if the message is not
+    /// a `/start` command of this bot or has no payload.
+    pub fn decode_start_payload<T: DeserializeOwned>(
+        &self,
+        message: &Message,
+    ) -> Option<Result<T, DeepLinkError>> {
+        self.start_payload(message).map(decode_payload)
+    }
+
+    fn link(&self, parameter: &str, payload: &str) -> Result<String, DeepLinkError> {
+        check_payload(payload)?;
+        Ok(format!(
+            "https://t.me/{}?{}={}",
+            self.username, parameter, payload
+        ))
+    }
+}
+
+/// Encode typed `payload` as base64url, see `encode_compact`.
+pub fn encode_payload<T: Serialize>(payload: &T) -> Result<String, DeepLinkError> {
+    let bytes = encode_compact(payload).map_err(DeepLinkError::Malformed)?;
+    let payload = BASE64_URL.encode(bytes);
+    check_payload(&payload)?;
+    Ok(payload)
+}
+
+/// Decode payload created with `encode_payload`.
+pub fn decode_payload<T: DeserializeOwned>(payload: &str) -> Result<T, DeepLinkError> {
+    check_payload(payload)?;
+    let bytes = BASE64_URL
+        .decode(payload)
+        .map_err(|_| DeepLinkError::InvalidBase64)?;
+    decode_compact(&bytes).map_err(DeepLinkError::Malformed)
+}
+
+fn check_payload(payload: &str) -> Result<(), DeepLinkError> {
+    if payload.is_empty() {
+        return Err(DeepLinkError::Empty);
+    }
+    if payload.len() > MAX_PAYLOAD_LENGTH {
+        return Err(DeepLinkError::TooLong(payload.len()));
+    }
+    let valid = payload
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    if !valid {
+        return Err(DeepLinkError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+impl fmt::Display for DeepLinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeepLinkError::Empty => f.write_str("payload is empty"),
+            DeepLinkError::TooLong(length) => write!(
+                f,
+                "payload is {} characters long, at most {} characters are allowed",
+                length, MAX_PAYLOAD_LENGTH
+            ),
+            DeepLinkError::InvalidCharacters => f.write_str("payload contains invalid characters"),
+            DeepLinkError::InvalidBase64 => f.write_str("payload is not valid base64url"),
+            DeepLinkError::Malformed(error) => write!(f, "malformed payload: {}", error),
+        }
+    }
+}
+
+impl error::Error for DeepLinkError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DeepLinkError::Malformed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Answering inline queries with paging and caching.
+//!
+//! [`InlineQueryAnswerer`] asks a result source for a page of results starting at the offset
+//! of the query, caps it at [`MAX_INLINE_RESULTS`], sets `next_offset` so that Telegram
+//! requests the next page when the user scrolls, and caches pages in-process.
+//!
+//! [`InlineQueryAnswerer`]: struct.InlineQueryAnswerer.html
+//! [`MAX_INLINE_RESULTS`]: constant.MAX_INLINE_RESULTS.html
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use futures::Future;
+
+use crate::prelude::CanAnswerInlineQuery;
+use crate::types::{AnswerInlineQuery, InlineQuery, InlineQueryResult, Integer, UserId};
+use crate::{Api, Error};
+
+/// Maximum number of results in one answer to an inline query.
+pub const MAX_INLINE_RESULTS: usize = 50;
+
+/// Default time results are cached for, the same as Telegram uses.
+pub const DEFAULT_CACHE_TIME: Duration = Duration::from_secs(300);
+
+/// Answers inline queries with results from an async source.
+///
+/// The source is called with the query and the offset of the first requested result and
+/// returns the results starting at that offset. At most `MAX_INLINE_RESULTS` of them are
+/// sent, and an empty page ends the paging.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::{Api, InlineQuery, InlineQueryResult, InlineQueryResultArticle};
+/// # use telegram_bot::{InputMessageContent, InputTextMessageContent};
+/// # use telegram_bot::util::inline_query::InlineQueryAnswerer;
+/// # async fn handle(api: Api, query: InlineQuery) -> Result<(), telegram_bot::Error> {
+/// let answerer = InlineQueryAnswerer::new(|query: InlineQuery, offset: usize| async move {
+///     (offset..offset + 10)
+///         .map(|i| {
+///             let title = format!("{} #{}", query.query, i);
+///             let content = InputTextMessageContent {
+///                 message_text: title.clone(),
+///                 parse_mode: None,
+///                 disable_web_page_preview: false,
+///             };
+///             InlineQueryResultArticle::new(i.to_string(), title, content).into()
+///         })
+///         .collect::<Vec<InlineQueryResult>>()
+/// });
+///
+/// answerer.answer(&api, &query).await
+/// # }
+/// ```
+pub struct InlineQueryAnswerer<F> {
+    source: F,
+    cache_time: Duration,
+    is_personal: bool,
+    cache: Mutex<HashMap<CacheKey, CacheEntry>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    query: String,
+    offset: usize,
+    user: Option<UserId>,
+}
+
+struct CacheEntry {
+    results: Vec<InlineQueryResult>,
+    expires: Instant,
+}
+
+impl<F, R> InlineQueryAnswerer<F>
+where
+    F: Fn(InlineQuery, usize) -> R,
+    R: Future<Output = Vec<InlineQueryResult>>,
+{
+    pub fn new(source: F) -> Self {
+        InlineQueryAnswerer {
+            source,
+            cache_time: DEFAULT_CACHE_TIME,
+            is_personal: false,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Time results are cached for, both in-process and by Telegram.
+    /// Zero disables the in-process cache.
+    pub fn cache_time(&mut self, cache_time: Duration) -> &mut Self {
+        self.cache_time = cache_time;
+        self
+    }
+
+    /// Results depend on the user sending the query, they are cached for each user separately.
+    pub fn is_personal(&mut self) -> &mut Self {
+        self.is_personal = true;
+        self
+    }
+
+    /// Build an answer to `query` using cached results if possible.
+    ///
+    /// An offset which wasn't produced by this answerer ends the paging with an empty answer.
+    pub async fn prepare(&self, query: &InlineQuery) -> AnswerInlineQuery {
+        let offset = match query.offset.as_str() {
+            "" => 0,
+            offset => match offset.parse() {
+                Ok(offset) => offset,
+                Err(_) => return self.build(query, 0, Vec::new()),
+            },
+        };
+        let key = CacheKey {
+            query: query.query.clone(),
+            offset,
+            user: if self.is_personal {
+                Some(query.from.id)
+            } else {
+                None
+            },
+        };
+
+        let results = match self.cached(&key) {
+            Some(results) => results,
+            None => {
+                let mut results = (self.source)(query.clone(), offset).await;
+                results.truncate(MAX_INLINE_RESULTS);
+                self.store(key, results.clone());
+                results
+            }
+        };
+
+        self.build(query, offset, results)
+    }
+
+    /// Answer `query` using cached results if possible.
+    pub async fn answer(&self, api: &Api, query: &InlineQuery) -> Result<(), Error> {
+        let answer = self.prepare(query).await;
+        api.send(answer).await
+    }
+
+    fn build(
+        &self,
+        query: &InlineQuery,
+        offset: usize,
+        results: Vec<InlineQueryResult>,
+    ) -> AnswerInlineQuery {
+        let count = results.len();
+        let mut answer = query.id.clone().answer(results);
+        answer.cache_time(self.cache_time.as_secs() as Integer);
+        if self.is_personal {
+            answer.is_personal();
+        }
+        if count > 0 {
+            answer.next_offset((offset + count).to_string());
+        }
+        answer
+    }
+
+    fn cached(&self, key: &CacheKey) -> Option<Vec<InlineQueryResult>> {
+        let cache = self.cache.lock().unwrap();
+        cache
+            .get(key)
+            .filter(|entry| entry.expires > Instant::now())
+            .map(|entry| entry.results.clone())
+    }
+
+    fn store(&self, key: CacheKey, results: Vec<InlineQueryResult>) {
+        if self.cache_time == Duration::from_secs(0) {
+            return;
+        }
+        let now = Instant::now();
+        let entry = CacheEntry {
+            results,
+            expires: now + self.cache_time,
+        };
+        let mut cache = self.cache.lock().unwrap();
+        // Expired entries are dropped here rather than on every lookup.
+        cache.retain(|_, entry| entry.expires > now);
+        cache.insert(key, entry);
+    }
+}
+
+impl<F> fmt::Debug for InlineQueryAnswerer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InlineQueryAnswerer")
+            .field("cache_time", &self.cache_time)
+            .field("is_personal", &self.is_personal)
+            .finish()
+    }
+}
@@ -0,0 +1,195 @@
+//! Verification of Telegram Login Widget data.
+//!
+//! The widget and `login_url` buttons pass the user data as query parameters signed with
+//! the bot token, see <https://core.telegram.org/widgets/login#checking-authorization>.
+
+use std::error;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use crate::types::{Integer, User, UserId};
+
+/// Default maximum age of login data.
+pub const DEFAULT_LOGIN_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Verifies login data against the bot token.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::util::login::LoginVerifier;
+/// # fn handle(query: Vec<(String, String)>) {
+/// let verifier = LoginVerifier::new("123456:token");
+/// match verifier.verify(query) {
+///     Ok(user) => println!("Logged in as {}", user.first_name),
+///     Err(error) => println!("Login failed: {}", error),
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LoginVerifier {
+    secret: [u8; 32],
+    max_age: Option<Duration>,
+}
+
+/// Error of login data or Web App init data verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginError {
+    /// Required field is missing.
+    MissingField(&'static str),
+    /// Field has an invalid value.
+    InvalidField(&'static str),
+    /// Hash doesn't match the data, the data was not signed with the bot token or was tampered.
+    InvalidHash,
+    /// Data is older than the allowed maximum age.
+    Expired,
+}
+
+impl LoginVerifier {
+    /// Create a verifier for the bot with `token`.
+    pub fn new<T: AsRef<str>>(token: T) -> Self {
+        let mut secret = [0; 32];
+        secret.copy_from_slice(&Sha256::digest(token.as_ref().as_bytes()));
+        LoginVerifier {
+            secret,
+            max_age: Some(DEFAULT_LOGIN_MAX_AGE),
+        }
+    }
+
+    /// Maximum age of the data by its `auth_date`, `None` disables the check.
+    /// Defaults to one day.
+    pub fn max_age(&mut self, max_age: Option<Duration>) -> &mut Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Verify decoded query parameters passed to the login URL and return the logged in user.
+    pub fn verify<I, K, V>(&self, params: I) -> Result<User, LoginError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.verify_at(params, SystemTime::now())
+    }
+
+    /// Verify parameters as if the current time was `now`.
+    pub fn verify_at<I, K, V>(&self, params: I, now: SystemTime) -> Result<User, LoginError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let params = collect_params(params);
+        check_hash(&self.secret, &params)?;
+        check_auth_date(&params, self.max_age, now)?;
+
+        let id = param(&params, "id")
+            .ok_or(LoginError::MissingField("id"))?
+            .parse::<Integer>()
+            .map_err(|_| LoginError::InvalidField("id"))?;
+        let first_name =
+            param(&params, "first_name").ok_or(LoginError::MissingField("first_name"))?;
+
+        Ok(User {
+            id: UserId::new(id),
+            first_name: first_name.to_owned(),
+            last_name: param(&params, "last_name").map(ToOwned::to_owned),
+            username: param(&params, "username").map(ToOwned::to_owned),
+            is_bot: false,
+            language_code: None,
+        })
+    }
+}
+
+impl fmt::Debug for LoginVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginVerifier")
+            .field("max_age", &self.max_age)
+            .finish()
+    }
+}
+
+impl fmt::Display for LoginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoginError::MissingField(field) => write!(f, "missing field `{}`", field),
+            LoginError::InvalidField(field) => write!(f, "invalid field `{}`", field),
+            LoginError::InvalidHash => f.write_str("hash doesn't match the data"),
+            LoginError::Expired => f.write_str("data is expired"),
+        }
+    }
+}
+
+impl error::Error for LoginError {}
+
+pub(crate) fn collect_params<I, K, V>(params: I) -> Vec<(String, String)>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut params = params
+        .into_iter()
+        .map(|(key, value)| (key.as_ref().to_owned(), value.as_ref().to_owned()))
+        .collect::<Vec<_>>();
+    params.sort();
+    params
+}
+
+pub(crate) fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Check the `hash` parameter against the data-check-string of sorted `params`.
+pub(crate) fn check_hash(secret: &[u8], params: &[(String, String)]) -> Result<(), LoginError> {
+    let hash = param(params, "hash").ok_or(LoginError::MissingField("hash"))?;
+    let hash = decode_hex(hash).ok_or(LoginError::InvalidHash)?;
+
+    let data_check_string = params
+        .iter()
+        .filter(|(key, _)| key != "hash")
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(data_check_string.as_bytes());
+    mac.verify_slice(&hash).map_err(|_| LoginError::InvalidHash)
+}
+
+pub(crate) fn check_auth_date(
+    params: &[(String, String)],
+    max_age: Option<Duration>,
+    now: SystemTime,
+) -> Result<(), LoginError> {
+    let auth_date = param(params, "auth_date")
+        .ok_or(LoginError::MissingField("auth_date"))?
+        .parse::<u64>()
+        .map_err(|_| LoginError::InvalidField("auth_date"))?;
+
+    if let Some(max_age) = max_age {
+        let auth_date = UNIX_EPOCH + Duration::from_secs(auth_date);
+        match now.duration_since(auth_date) {
+            Ok(age) if age > max_age => return Err(LoginError::Expired),
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
@@ -0,0 +1,279 @@
+//! Nested menus on inline keyboards.
+//!
+//! A [`Menu`] is a tree of submenus and leaves. Submenus are shown by editing the text and the
+//! keyboard of the same message in place, with "« Back" and "« Home" buttons for navigation.
+//! Selecting a leaf calls its callback.
+//!
+//! [`Menu`]: struct.Menu.html
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::formatting::FormattedText;
+use crate::prelude::{CanAnswerCallbackQuery, CanEditMessageText, CanSendMessage};
+use crate::types::{
+    encode_callback_data, AnswerCallbackQuery, CallbackDataError, CallbackDataKey, CallbackQuery,
+    EditMessageText, InlineKeyboardButton, InlineKeyboardMarkup, SendMessage, ToChatRef,
+};
+
+/// Label of the button returning to the parent submenu.
+pub const BACK_LABEL: &str = "« Back";
+
+/// Label of the button returning to the root of the menu.
+pub const HOME_LABEL: &str = "« Home";
+
+/// Tree of inline keyboard menus.
+///
+/// Buttons carry the menu `id` and the path to the node in their callback data signed with
+/// `key`, so several menus can be used by the same bot as long as their ids are different.
+/// Paths are limited by the size of callback data, which allows about 30 levels of nesting
+/// with a short id.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::{CallbackDataKey, ChatId, prelude::*};
+/// # use telegram_bot::util::menu::{Menu, MenuNode};
+/// #[derive(Debug, PartialEq)]
+/// enum Frequency {
+///     Daily,
+///     Weekly,
+/// }
+///
+/// let mut root = MenuNode::root("Settings");
+/// root.add_submenu("Notifications", "Notification settings")
+///     .add_submenu("Frequency", "How often should we notify you?")
+///     .add_leaf("Daily", |_| Frequency::Daily)
+///     .add_leaf("Weekly", |_| Frequency::Weekly);
+///
+/// let key = CallbackDataKey::new("bot token");
+/// let menu = Menu::new("settings", &key, root).unwrap();
+/// let request = menu.show(ChatId::new(61031));
+/// # drop(request);
+/// ```
+pub struct Menu<R> {
+    id: String,
+    key: CallbackDataKey,
+    root: MenuNode<R>,
+}
+
+/// Node of a [`Menu`](struct.Menu.html), either a submenu or a leaf.
+pub struct MenuNode<R> {
+    label: String,
+    kind: MenuNodeKind<R>,
+}
+
+enum MenuNodeKind<R> {
+    Submenu {
+        text: FormattedText,
+        children: Vec<MenuNode<R>>,
+    },
+    Leaf(Arc<dyn Fn(&CallbackQuery) -> R + Send + Sync>),
+}
+
+/// Requests and the result produced by a press of a menu button.
+#[derive(Debug, Clone)]
+pub struct MenuUpdate<R> {
+    /// Request showing the selected submenu, `None` if a leaf was selected
+    /// or the message is not available.
+    pub edit: Option<EditMessageText<'static>>,
+    /// Answer to the callback query, which must be sent in any case.
+    /// Replace it to show a notification after a leaf was selected.
+    pub answer: AnswerCallbackQuery<'static>,
+    /// Result of the callback of the selected leaf.
+    pub result: Option<R>,
+}
+
+impl<R> Menu<R> {
+    /// Create a menu from the `root` submenu. Fails if `id` and the path to some node
+    /// don't fit into callback data together, i.e. if the menu is nested too deep.
+    pub fn new<S>(
+        id: S,
+        key: &CallbackDataKey,
+        root: MenuNode<R>,
+    ) -> Result<Self, CallbackDataError>
+    where
+        S: Into<String>,
+    {
+        let menu = Menu {
+            id: id.into(),
+            key: key.clone(),
+            root,
+        };
+        menu.check(&menu.root, &mut Vec::new())?;
+        Ok(menu)
+    }
+
+    /// Send the root of the menu to the chat.
+    pub fn show<C: ToChatRef>(&self, chat: C) -> SendMessage<'static> {
+        let mut request = chat.text("");
+        if let MenuNodeKind::Submenu { ref text, .. } = self.root.kind {
+            request.formatted_text(text);
+        }
+        request.reply_markup(self.keyboard(&[]));
+        request
+    }
+
+    /// Keyboard of the submenu at `path`, empty if there is no submenu at `path`.
+    pub fn keyboard(&self, path: &[usize]) -> InlineKeyboardMarkup {
+        let mut keyboard = InlineKeyboardMarkup::new();
+        let children = match self.node(path) {
+            Some(MenuNode {
+                kind: MenuNodeKind::Submenu { children, .. },
+                ..
+            }) => children,
+            _ => return keyboard,
+        };
+
+        let mut child_path = path.to_vec();
+        for (index, child) in children.iter().enumerate() {
+            child_path.push(index);
+            keyboard.add_row(vec![self.button(&child.label, &child_path)]);
+            child_path.pop();
+        }
+
+        if let Some((_, parent)) = path.split_last() {
+            let navigation = keyboard.add_row(vec![self.button(BACK_LABEL, parent)]);
+            if !parent.is_empty() {
+                navigation.push(self.button(HOME_LABEL, &[]));
+            }
+        }
+        keyboard
+    }
+
+    /// Handle a press of a button of the menu. Returns `None` if the query doesn't belong
+    /// to this menu or refers to a node that doesn't exist.
+    pub fn handle(&self, query: &CallbackQuery) -> Option<MenuUpdate<R>> {
+        let (id, path) = query.decode_data::<(String, Vec<usize>)>(&self.key).ok()?;
+        if id != self.id {
+            return None;
+        }
+
+        let update = match self.node(&path)?.kind {
+            MenuNodeKind::Submenu { ref text, .. } => MenuUpdate {
+                edit: query.message.as_ref().map(|message| {
+                    let mut edit = message.edit_text("");
+                    edit.formatted_text(text).reply_markup(self.keyboard(&path));
+                    edit
+                }),
+                answer: query.acknowledge(),
+                result: None,
+            },
+            MenuNodeKind::Leaf(ref callback) => MenuUpdate {
+                edit: None,
+                answer: query.acknowledge(),
+                result: Some(callback(query)),
+            },
+        };
+        Some(update)
+    }
+
+    fn node(&self, path: &[usize]) -> Option<&MenuNode<R>> {
+        path.iter()
+            .try_fold(&self.root, |node, &index| match node.kind {
+                MenuNodeKind::Submenu { ref children, .. } => children.get(index),
+                MenuNodeKind::Leaf(_) => None,
+            })
+    }
+
+    /// Check that buttons of `node` at `path` and of all its descendants can be encoded.
+    fn check(&self, node: &MenuNode<R>, path: &mut Vec<usize>) -> Result<(), CallbackDataError> {
+        encode_callback_data(&self.key, &(&self.id, &path))?;
+        if let MenuNodeKind::Submenu { ref children, .. } = node.kind {
+            for (index, child) in children.iter().enumerate() {
+                path.push(index);
+                self.check(child, path)?;
+                path.pop();
+            }
+        }
+        Ok(())
+    }
+
+    fn button(&self, label: &str, path: &[usize]) -> InlineKeyboardButton {
+        // Paths of all nodes were encoded successfully in `new`.
+        InlineKeyboardButton::callback_data(label, &self.key, &(&self.id, path))
+            .expect("menu paths were checked in new")
+    }
+}
+
+impl<R> MenuNode<R> {
+    /// Root submenu of a menu showing `text`.
+    pub fn root<T: Into<FormattedText>>(text: T) -> Self {
+        MenuNode::submenu(String::new(), text.into())
+    }
+
+    fn submenu(label: String, text: FormattedText) -> Self {
+        MenuNode {
+            label,
+            kind: MenuNodeKind::Submenu {
+                text,
+                children: Vec::new(),
+            },
+        }
+    }
+
+    /// Label of the button opening the node.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Add a nested submenu showing `text` when opened, returns the new submenu.
+    pub fn add_submenu<L, T>(&mut self, label: L, text: T) -> &mut MenuNode<R>
+    where
+        L: Into<String>,
+        T: Into<FormattedText>,
+    {
+        let children = self.children();
+        children.push(MenuNode::submenu(label.into(), text.into()));
+        children.last_mut().unwrap()
+    }
+
+    /// Add a leaf calling `callback` when selected, returns this submenu.
+    pub fn add_leaf<L, F>(&mut self, label: L, callback: F) -> &mut Self
+    where
+        L: Into<String>,
+        F: Fn(&CallbackQuery) -> R + Send + Sync + 'static,
+    {
+        self.children().push(MenuNode {
+            label: label.into(),
+            kind: MenuNodeKind::Leaf(Arc::new(callback)),
+        });
+        self
+    }
+
+    fn children(&mut self) -> &mut Vec<MenuNode<R>> {
+        match self.kind {
+            MenuNodeKind::Submenu {
+                ref mut children, ..
+            } => children,
+            // Leaves are never handed out by `add_submenu` or `add_leaf`.
+            MenuNodeKind::Leaf(_) => unreachable!("leaves have no children"),
+        }
+    }
+}
+
+impl<R> fmt::Debug for MenuNode<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            MenuNodeKind::Submenu {
+                ref text,
+                ref children,
+            } => f
+                .debug_struct("Submenu")
+                .field("label", &self.label)
+                .field("text", text)
+                .field("children", children)
+                .finish(),
+            MenuNodeKind::Leaf(_) => f.debug_struct("Leaf").field("label", &self.label).finish(),
+        }
+    }
+}
+
+impl<R> fmt::Debug for Menu<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Menu")
+            .field("id", &self.id)
+            .field("root", &self.root)
+            .finish()
+    }
+}
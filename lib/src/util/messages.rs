@@ -35,7 +35,7 @@ impl MessageText for MessageKind {
         match self {
             MessageKind::Text { data, .. } => Some(data.to_owned()),
             MessageKind::Audio { data } => data.title.to_owned(),
-            MessageKind::Document { data, caption } => {
+            MessageKind::Document { data, caption, .. } => {
                 caption.clone().or_else(|| data.file_name.clone())
             }
             MessageKind::Photo { caption, .. } => caption.to_owned(),
@@ -58,6 +58,18 @@ impl MessageText for MessageKind {
             MessageKind::MigrateToChatId { .. } => None,
             MessageKind::MigrateFromChatId { .. } => None,
             MessageKind::PinnedMessage { data } => data.text(),
+            MessageKind::Invoice { .. } => None,
+            MessageKind::SuccessfulPayment { .. } => None,
+            MessageKind::Game { .. } => None,
+            MessageKind::Dice { .. } => None,
+            MessageKind::ProximityAlertTriggered { .. } => None,
+            MessageKind::MessageAutoDeleteTimerChanged { .. } => None,
+            MessageKind::VideoChatScheduled { .. } => None,
+            MessageKind::VideoChatStarted => None,
+            MessageKind::VideoChatEnded { .. } => None,
+            MessageKind::VideoChatParticipantsInvited { .. } => None,
+            MessageKind::WebAppData { .. } => None,
+            MessageKind::PassportData { .. } => None,
             MessageKind::Unknown { .. } => None,
         }
     }
@@ -142,6 +154,42 @@ impl MessageGetFiles for MessageKind {
             MessageKind::MigrateToChatId { .. } => None,
             MessageKind::MigrateFromChatId { .. } => None,
             MessageKind::PinnedMessage { .. } => None,
+            MessageKind::Invoice { .. } => None,
+            MessageKind::SuccessfulPayment { .. } => None,
+            MessageKind::Game { data } => {
+                let mut files: Vec<GetFile> = data.photo.iter().map(|f| f.get_file()).collect();
+                if let Some(animation) = &data.animation {
+                    files.push(animation.get_file());
+                }
+                Some(files)
+            }
+            MessageKind::Dice { .. } => None,
+            MessageKind::ProximityAlertTriggered { .. } => None,
+            MessageKind::MessageAutoDeleteTimerChanged { .. } => None,
+            MessageKind::VideoChatScheduled { .. } => None,
+            MessageKind::VideoChatStarted => None,
+            MessageKind::VideoChatEnded { .. } => None,
+            MessageKind::VideoChatParticipantsInvited { .. } => None,
+            MessageKind::WebAppData { .. } => None,
+            MessageKind::PassportData { data } => {
+                let mut files = Vec::new();
+                for element in &data.data {
+                    let element_files = element.files.iter().flatten();
+                    let sides = element
+                        .front_side
+                        .iter()
+                        .chain(&element.reverse_side)
+                        .chain(&element.selfie);
+                    let translation = element.translation.iter().flatten();
+                    files.extend(
+                        element_files
+                            .chain(sides)
+                            .chain(translation)
+                            .map(|f| f.get_file()),
+                    );
+                }
+                Some(files)
+            }
             MessageKind::Unknown { .. } => None,
         }
     }
@@ -1,3 +1,12 @@
-//! Traits that extends raw types functionality
+//! Traits and helpers that extend raw types functionality
 
+pub mod chosen_inline_result;
+pub mod deep_link;
+pub mod inline_query;
+pub mod login;
+pub mod menu;
 pub mod messages;
+pub mod paginator;
+#[cfg(feature = "passport")]
+pub mod passport;
+pub mod web_app;
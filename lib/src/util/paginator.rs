@@ -0,0 +1,212 @@
+//! Inline keyboard pagination.
+//!
+//! [`Paginator`] splits a list of items into pages, renders a page as an inline keyboard with
+//! a "◀ 2/17 ▶" navigation row and turns presses of the navigation buttons into requests
+//! which edit the message in place.
+//!
+//! [`Paginator`]: struct.Paginator.html
+
+use crate::formatting::FormattedText;
+use crate::prelude::{CanAnswerCallbackQuery, CanEditMessageReplyMarkup, CanEditMessageText};
+use crate::types::{
+    encode_callback_data, AnswerCallbackQuery, CallbackDataError, CallbackDataKey, CallbackQuery,
+    EditMessageReplyMarkup, EditMessageText, InlineKeyboardButton, InlineKeyboardMarkup,
+};
+
+/// Default number of items per page.
+pub const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// Splits items into pages of an inline keyboard.
+///
+/// Navigation buttons carry the paginator `id` and the target page in their callback data
+/// signed with `key`, so several paginators can be used by the same bot as long as their
+/// ids are different.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::{CallbackDataKey, InlineKeyboardButton};
+/// # use telegram_bot::util::paginator::Paginator;
+/// let key = CallbackDataKey::new("bot token");
+/// let items = (1..=45).map(|i| format!("Item {}", i)).collect::<Vec<_>>();
+/// let mut paginator = Paginator::new("items", &key, items).unwrap();
+/// paginator.page_size(10);
+///
+/// let keyboard = paginator.keyboard(1, |item| InlineKeyboardButton::callback(item, item));
+/// let rows = keyboard.inline_keyboard();
+/// assert_eq!(rows.len(), 11);
+/// assert_eq!(rows[0][0].text(), "Item 11");
+///
+/// let navigation = rows.last().unwrap();
+/// let labels = navigation.iter().map(|b| b.text()).collect::<Vec<_>>();
+/// assert_eq!(labels, vec!["◀", "2/5", "▶"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Paginator<T> {
+    id: String,
+    key: CallbackDataKey,
+    items: Vec<T>,
+    page_size: usize,
+}
+
+/// Requests produced by a press of a navigation button.
+#[derive(Debug, Clone)]
+pub struct PageUpdate<E> {
+    /// Request editing the message, `None` if the page didn't change or the
+    /// message is not available.
+    pub edit: Option<E>,
+    /// Answer to the callback query, which must be sent in any case.
+    pub answer: AnswerCallbackQuery<'static>,
+}
+
+impl<T> Paginator<T> {
+    /// Create a paginator. Fails if `id` is too long to fit into callback data
+    /// together with a page number.
+    pub fn new<S: Into<String>>(
+        id: S,
+        key: &CallbackDataKey,
+        items: Vec<T>,
+    ) -> Result<Self, CallbackDataError> {
+        let id = id.into();
+        encode_callback_data(key, &(&id, Some(usize::MAX)))?;
+        Ok(Paginator {
+            id,
+            key: key.clone(),
+            items,
+            page_size: DEFAULT_PAGE_SIZE,
+        })
+    }
+
+    /// Number of items per page.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is zero.
+    pub fn page_size(&mut self, page_size: usize) -> &mut Self {
+        assert!(page_size > 0, "page size must be positive");
+        self.page_size = page_size;
+        self
+    }
+
+    /// Number of pages, at least one.
+    pub fn pages(&self) -> usize {
+        self.items.len().div_ceil(self.page_size).max(1)
+    }
+
+    /// Items of the `page` counting from zero, the last page if `page` is out of range.
+    pub fn items(&self, page: usize) -> &[T] {
+        let start = self.clamp(page) * self.page_size;
+        let end = (start + self.page_size).min(self.items.len());
+        &self.items[start.min(end)..end]
+    }
+
+    /// Navigation row of the `page`, empty if there is only one page.
+    pub fn navigation(&self, page: usize) -> Vec<InlineKeyboardButton> {
+        let page = self.clamp(page);
+        let pages = self.pages();
+        let mut row = Vec::new();
+        if pages == 1 {
+            return row;
+        }
+
+        if page > 0 {
+            row.push(self.button("◀", Some(page - 1)));
+        }
+        row.push(self.button(format!("{}/{}", page + 1, pages), None));
+        if page + 1 < pages {
+            row.push(self.button("▶", Some(page + 1)));
+        }
+        row
+    }
+
+    /// Keyboard with a row per item of the `page` followed by the navigation row.
+    pub fn keyboard<F>(&self, page: usize, mut button: F) -> InlineKeyboardMarkup
+    where
+        F: FnMut(&T) -> InlineKeyboardButton,
+    {
+        let mut keyboard = InlineKeyboardMarkup::new();
+        for item in self.items(page) {
+            keyboard.add_row(vec![button(item)]);
+        }
+        let navigation = self.navigation(page);
+        if !navigation.is_empty() {
+            keyboard.add_row(navigation);
+        }
+        keyboard
+    }
+
+    /// Page requested by the callback query, `None` if the query doesn't belong
+    /// to this paginator. `Some(None)` is returned for presses of the page counter.
+    pub fn requested_page(&self, query: &CallbackQuery) -> Option<Option<usize>> {
+        let (id, page) = query
+            .decode_data::<(String, Option<usize>)>(&self.key)
+            .ok()?;
+        if id != self.id {
+            return None;
+        }
+        Some(page.map(|page| self.clamp(page)))
+    }
+
+    /// Handle a press of a navigation button of a keyboard created with `keyboard`,
+    /// editing the reply markup of the message.
+    pub fn update_keyboard<F>(
+        &self,
+        query: &CallbackQuery,
+        button: F,
+    ) -> Option<PageUpdate<EditMessageReplyMarkup>>
+    where
+        F: FnMut(&T) -> InlineKeyboardButton,
+    {
+        let page = self.requested_page(query)?;
+        let edit = match (page, &query.message) {
+            (Some(page), Some(message)) => {
+                Some(message.edit_reply_markup(Some(self.keyboard(page, button))))
+            }
+            _ => None,
+        };
+        Some(PageUpdate {
+            edit,
+            answer: query.acknowledge(),
+        })
+    }
+
+    /// Handle a press of a navigation button of a text page, editing the text of the message
+    /// to the one returned by `render` and the keyboard to the navigation row.
+    pub fn update_text<F, R>(
+        &self,
+        query: &CallbackQuery,
+        render: F,
+    ) -> Option<PageUpdate<EditMessageText<'static>>>
+    where
+        F: FnOnce(&[T]) -> R,
+        R: Into<FormattedText>,
+    {
+        let page = self.requested_page(query)?;
+        let edit = match (page, &query.message) {
+            (Some(page), Some(message)) => {
+                let mut edit = message.edit_text("");
+                edit.formatted_text(render(self.items(page)));
+                let navigation = self.navigation(page);
+                if !navigation.is_empty() {
+                    edit.reply_markup(InlineKeyboardMarkup::from(vec![navigation]));
+                }
+                Some(edit)
+            }
+            _ => None,
+        };
+        Some(PageUpdate {
+            edit,
+            answer: query.acknowledge(),
+        })
+    }
+
+    fn clamp(&self, page: usize) -> usize {
+        page.min(self.pages() - 1)
+    }
+
+    fn button<S: AsRef<str>>(&self, text: S, page: Option<usize>) -> InlineKeyboardButton {
+        // The longest data was encoded successfully in `new`.
+        InlineKeyboardButton::callback_data(text, &self.key, &(&self.id, page))
+            .expect("paginator id was checked in new")
+    }
+}
@@ -0,0 +1,175 @@
+//! Decryption of Telegram Passport data.
+//!
+//! Credentials are encrypted with the bot's public RSA key, and contain secrets and hashes
+//! used to decrypt element data and files with AES-256-CBC,
+//! see <https://core.telegram.org/passport#decrypting-data>.
+
+use std::error;
+use std::fmt;
+
+use aes::Aes256;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use cbc::cipher::block_padding::NoPadding;
+use cbc::cipher::{BlockDecryptMut, KeyIvInit};
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::{Oaep, RsaPrivateKey};
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::types::{Credentials, DataCredentials, EncryptedCredentials, FileCredentials};
+
+/// Decrypts Telegram Passport credentials with the bot's private key.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use telegram_bot::PassportData;
+/// # use telegram_bot::util::passport::{decrypt_element_data, PassportDecryptor};
+/// # fn handle(passport: PassportData) -> Result<(), Box<dyn std::error::Error>> {
+/// let decryptor = PassportDecryptor::from_pem(&std::fs::read_to_string("private.key")?)?;
+/// let credentials = decryptor.decrypt_credentials(&passport.credentials)?;
+///
+/// for element in &passport.data {
+///     let value = credentials.secure_data.get(&element.kind);
+///     if let (Some(data), Some(credentials)) = (&element.data, value.and_then(|v| v.data.as_ref())) {
+///         let json = decrypt_element_data(data, credentials)?;
+///         println!("{}", String::from_utf8_lossy(&json));
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PassportDecryptor {
+    key: RsaPrivateKey,
+}
+
+/// Error of Telegram Passport data decryption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PassportError {
+    /// Private key can't be parsed.
+    InvalidKey,
+    /// Value is not valid base64.
+    InvalidBase64,
+    /// Credentials secret can't be decrypted with the private key.
+    InvalidSecret,
+    /// Data length is not a multiple of the block size or its padding is malformed.
+    InvalidData,
+    /// Hash of decrypted data doesn't match, the data is corrupted or was tampered.
+    HashMismatch,
+    /// Decrypted credentials are not valid JSON.
+    InvalidCredentials(String),
+}
+
+impl PassportDecryptor {
+    pub fn new(key: RsaPrivateKey) -> Self {
+        PassportDecryptor { key }
+    }
+
+    /// Parse a PEM-encoded PKCS#1 or PKCS#8 private key.
+    pub fn from_pem(pem: &str) -> Result<Self, PassportError> {
+        RsaPrivateKey::from_pkcs1_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))
+            .map(PassportDecryptor::new)
+            .map_err(|_| PassportError::InvalidKey)
+    }
+
+    /// Decrypt and authenticate credentials.
+    pub fn decrypt_credentials(
+        &self,
+        credentials: &EncryptedCredentials,
+    ) -> Result<Credentials, PassportError> {
+        let secret = self
+            .key
+            .decrypt(
+                Oaep::new::<sha1::Sha1>(),
+                &decode_base64(&credentials.secret)?,
+            )
+            .map_err(|_| PassportError::InvalidSecret)?;
+        let data = decrypt_data(
+            &decode_base64(&credentials.data)?,
+            &secret,
+            &decode_base64(&credentials.hash)?,
+        )?;
+        serde_json::from_slice(&data).map_err(|e| PassportError::InvalidCredentials(e.to_string()))
+    }
+}
+
+/// Decrypt base64-encoded `EncryptedPassportElement::data`, returns JSON.
+pub fn decrypt_element_data(
+    data: &str,
+    credentials: &DataCredentials,
+) -> Result<Vec<u8>, PassportError> {
+    decrypt_data(
+        &decode_base64(data)?,
+        &decode_base64(&credentials.secret)?,
+        &decode_base64(&credentials.data_hash)?,
+    )
+}
+
+/// Decrypt contents of a downloaded `PassportFile`.
+pub fn decrypt_file(data: &[u8], credentials: &FileCredentials) -> Result<Vec<u8>, PassportError> {
+    decrypt_data(
+        data,
+        &decode_base64(&credentials.secret)?,
+        &decode_base64(&credentials.file_hash)?,
+    )
+}
+
+/// Decrypt `data` with `secret` and check it against `hash`, as described by the spec.
+pub fn decrypt_data(data: &[u8], secret: &[u8], hash: &[u8]) -> Result<Vec<u8>, PassportError> {
+    let secret_hash = Sha512::new()
+        .chain_update(secret)
+        .chain_update(hash)
+        .finalize();
+    let (key, iv) = (&secret_hash[..32], &secret_hash[32..48]);
+
+    let decryptor =
+        cbc::Decryptor::<Aes256>::new_from_slices(key, iv).expect("key and iv have valid lengths");
+    let mut decrypted = data.to_vec();
+    let length = decryptor
+        .decrypt_padded_mut::<NoPadding>(&mut decrypted)
+        .map_err(|_| PassportError::InvalidData)?
+        .len();
+    decrypted.truncate(length);
+
+    if Sha256::digest(&decrypted).as_slice() != hash {
+        return Err(PassportError::HashMismatch);
+    }
+
+    let padding = decrypted.first().copied().unwrap_or(0) as usize;
+    if padding < 32 || padding > decrypted.len() {
+        return Err(PassportError::InvalidData);
+    }
+    Ok(decrypted[padding..].to_vec())
+}
+
+fn decode_base64(data: &str) -> Result<Vec<u8>, PassportError> {
+    BASE64
+        .decode(data)
+        .map_err(|_| PassportError::InvalidBase64)
+}
+
+impl fmt::Debug for PassportDecryptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PassportDecryptor").finish()
+    }
+}
+
+impl fmt::Display for PassportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PassportError::InvalidKey => f.write_str("invalid private key"),
+            PassportError::InvalidBase64 => f.write_str("invalid base64"),
+            PassportError::InvalidSecret => f.write_str("can't decrypt credentials secret"),
+            PassportError::InvalidData => f.write_str("invalid encrypted data"),
+            PassportError::HashMismatch => f.write_str("hash doesn't match the data"),
+            PassportError::InvalidCredentials(error) => {
+                write!(f, "invalid credentials: {}", error)
+            }
+        }
+    }
+}
+
+impl error::Error for PassportError {}
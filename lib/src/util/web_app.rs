@@ -0,0 +1,158 @@
+//! Validation of Web App init data.
+//!
+//! Web Apps receive `Telegram.WebApp.initData`, a query string signed with the bot token,
+//! which should be passed to the bot server and validated before trusting any of its fields,
+//! see <https://core.telegram.org/bots/webapps#validating-data-received-via-the-mini-app>.
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::types::{Integer, User};
+use crate::util::login::{
+    check_auth_date, check_hash, collect_params, param, LoginError, DEFAULT_LOGIN_MAX_AGE,
+};
+
+/// Validated Web App init data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebAppInitData {
+    /// Unique identifier of the Web App session, required for `AnswerWebAppQuery`.
+    pub query_id: Option<String>,
+    /// The user who opened the Web App.
+    pub user: Option<User>,
+    /// The chat partner of the current user in a private chat the Web App was opened from.
+    pub receiver: Option<User>,
+    /// Type of the chat from which the Web App was opened.
+    pub chat_type: Option<String>,
+    /// Global identifier of the chat from which the Web App was opened.
+    pub chat_instance: Option<String>,
+    /// The value of the `startattach` parameter passed via link.
+    pub start_param: Option<String>,
+    /// Unix time when the form was opened.
+    pub auth_date: Integer,
+}
+
+/// Validates Web App init data against the bot token.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::util::web_app::WebAppValidator;
+/// # fn handle(init_data: &str) {
+/// let validator = WebAppValidator::new("123456:token");
+/// match validator.validate(init_data) {
+///     Ok(data) => println!("Opened by {:?}", data.user),
+///     Err(error) => println!("Invalid init data: {}", error),
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WebAppValidator {
+    secret: [u8; 32],
+    max_age: Option<Duration>,
+}
+
+impl WebAppValidator {
+    /// Create a validator for the bot with `token`.
+    pub fn new<T: AsRef<str>>(token: T) -> Self {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(b"WebAppData").expect("HMAC accepts keys of any length");
+        mac.update(token.as_ref().as_bytes());
+
+        let mut secret = [0; 32];
+        secret.copy_from_slice(&mac.finalize().into_bytes());
+        WebAppValidator {
+            secret,
+            max_age: Some(DEFAULT_LOGIN_MAX_AGE),
+        }
+    }
+
+    /// Maximum age of the data by its `auth_date`, `None` disables the check.
+    /// Defaults to one day.
+    pub fn max_age(&mut self, max_age: Option<Duration>) -> &mut Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Validate the raw `initData` query string.
+    pub fn validate(&self, init_data: &str) -> Result<WebAppInitData, LoginError> {
+        self.validate_at(init_data, SystemTime::now())
+    }
+
+    /// Validate the raw `initData` query string as if the current time was `now`.
+    pub fn validate_at(
+        &self,
+        init_data: &str,
+        now: SystemTime,
+    ) -> Result<WebAppInitData, LoginError> {
+        let params = init_data
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let mut parts = pair.splitn(2, '=');
+                let key = decode_component(parts.next().unwrap_or_default())?;
+                let value = decode_component(parts.next().unwrap_or_default())?;
+                Ok((key, value))
+            })
+            .collect::<Result<Vec<_>, LoginError>>()?;
+        let params = collect_params(params);
+
+        check_hash(&self.secret, &params)?;
+        check_auth_date(&params, self.max_age, now)?;
+
+        let user = |name: &'static str| {
+            param(&params, name)
+                .map(|user| serde_json::from_str(user).map_err(|_| LoginError::InvalidField(name)))
+                .transpose()
+        };
+        let string = |name| param(&params, name).map(ToOwned::to_owned);
+
+        Ok(WebAppInitData {
+            query_id: string("query_id"),
+            user: user("user")?,
+            receiver: user("receiver")?,
+            chat_type: string("chat_type"),
+            chat_instance: string("chat_instance"),
+            start_param: string("start_param"),
+            auth_date: param(&params, "auth_date")
+                .and_then(|date| date.parse().ok())
+                .ok_or(LoginError::InvalidField("auth_date"))?,
+        })
+    }
+}
+
+impl fmt::Debug for WebAppValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebAppValidator")
+            .field("max_age", &self.max_age)
+            .finish()
+    }
+}
+
+/// Decode a percent-encoded query string component.
+fn decode_component(component: &str) -> Result<String, LoginError> {
+    let invalid = || LoginError::InvalidField("init_data");
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = component.get(i + 1..i + 3).ok_or_else(invalid)?;
+                decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+                i += 3;
+            }
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| invalid())
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use std::time::{Duration, SystemTime};
+
+use telegram_bot::prelude::*;
+use telegram_bot::util::chosen_inline_result::InlineResultStore;
+use telegram_bot::{
+    AnswerInlineQuery, ChosenInlineResult, InlineQuery, InlineQueryResultArticle,
+    InputTextMessageContent,
+};
+
+use common::{chosen_inline_result, inline_query};
+
+fn answer(query: &InlineQuery) -> AnswerInlineQuery {
+    let results = (0..3)
+        .map(|i| {
+            let title = format!("{} #{}", query.query, i);
+            let content = InputTextMessageContent {
+                message_text: title.clone(),
+                parse_mode: None,
+                disable_web_page_preview: false,
+            };
+            InlineQueryResultArticle::new(i.to_string(), title, content).into()
+        })
+        .collect();
+    query.id.clone().answer(results)
+}
+
+fn title(
+    store: &InlineResultStore,
+    chosen: &ChosenInlineResult,
+    now: SystemTime,
+) -> Option<String> {
+    store.resolve_at(chosen, now).map(|served| {
+        assert_eq!(served.query.query, chosen.query);
+        match served.result {
+            telegram_bot::InlineQueryResult::InlineQueryResultArticle(article) => article.title,
+            result => panic!("unexpected result: {:?}", result),
+        }
+    })
+}
+
+#[test]
+fn resolves_served_result() {
+    let store = InlineResultStore::new();
+    let now = SystemTime::now();
+    for text in &["cats", "dogs"] {
+        let query = inline_query(1, text, "");
+        store.record_at(&query, &answer(&query), now);
+    }
+
+    assert_eq!(
+        title(&store, &chosen_inline_result(1, "cats", "1"), now),
+        Some("cats #1".into())
+    );
+    assert_eq!(
+        title(&store, &chosen_inline_result(1, "dogs", "1"), now),
+        Some("dogs #1".into())
+    );
+    assert_eq!(
+        title(&store, &chosen_inline_result(1, "cats", "7"), now),
+        None
+    );
+    // Telegram serves cached answers which are not personal to every user.
+    assert_eq!(
+        title(&store, &chosen_inline_result(2, "cats", "1"), now),
+        Some("cats #1".into())
+    );
+}
+
+#[test]
+fn personal_results_resolve_for_their_user_only() {
+    let store = InlineResultStore::new();
+    let now = SystemTime::now();
+    let query = inline_query(1, "cats", "");
+    let mut answer = answer(&query);
+    answer.is_personal();
+    store.record_at(&query, &answer, now);
+
+    assert_eq!(
+        title(&store, &chosen_inline_result(1, "cats", "1"), now),
+        Some("cats #1".into())
+    );
+    assert_eq!(
+        title(&store, &chosen_inline_result(2, "cats", "1"), now),
+        None
+    );
+}
+
+#[test]
+fn remembers_results_while_telegram_caches_them() {
+    let store = InlineResultStore::new();
+    let now = SystemTime::now();
+    let query = inline_query(1, "cats", "");
+    let mut answer = answer(&query);
+    answer.cache_time(3600);
+    store.record_at(&query, &answer, now);
+
+    let picked = chosen_inline_result(2, "cats", "0");
+    let cached = now + Duration::from_secs(3600 + 599);
+    assert_eq!(title(&store, &picked, cached), Some("cats #0".into()));
+    let expired = now + Duration::from_secs(3600 + 601);
+    assert_eq!(title(&store, &picked, expired), None);
+}
+
+#[test]
+fn forgets_results_after_window() {
+    let mut store = InlineResultStore::new();
+    store.window(Duration::from_secs(60));
+    let now = SystemTime::now();
+    let cats = inline_query(1, "cats", "");
+    let mut cats_answer = answer(&cats);
+    cats_answer.cache_time(0);
+    store.record_at(&cats, &cats_answer, now);
+
+    let picked = chosen_inline_result(1, "cats", "0");
+    let later = now + Duration::from_secs(59);
+    assert_eq!(title(&store, &picked, later), Some("cats #0".into()));
+    let expired = now + Duration::from_secs(61);
+    assert_eq!(title(&store, &picked, expired), None);
+
+    let dogs = inline_query(1, "dogs", "");
+    store.record_at(&dogs, &answer(&dogs), expired);
+    assert_eq!(title(&store, &picked, now), None);
+}
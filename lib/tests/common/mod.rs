@@ -0,0 +1,77 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use serde_json::{json, Value};
+
+use telegram_bot::{
+    CallbackDataKey, CallbackQuery, ChosenInlineResult, InlineKeyboardButtonKind,
+    InlineKeyboardMarkup, InlineQuery, Message,
+};
+
+/// Key the callback data of the test keyboards is signed with.
+pub fn key() -> CallbackDataKey {
+    CallbackDataKey::new("123456:secret-token")
+}
+
+pub fn user(id: i64) -> Value {
+    json!({"id": id, "is_bot": false, "first_name": "Fedor"})
+}
+
+fn message_value(from: Value, text: &str) -> Value {
+    json!({
+        "message_id": 7,
+        "from": from,
+        "date": 1625090000,
+        "chat": {"id": 42, "type": "private", "first_name": "Fedor"},
+        "text": text
+    })
+}
+
+/// Text message of the user 42 in the private chat with the bot.
+pub fn message(text: &str) -> Message {
+    serde_json::from_value(message_value(user(42), text)).unwrap()
+}
+
+/// Press of the button labeled `label` by the user 42, the keyboard is attached to
+/// the message 7 of the bot.
+pub fn press(keyboard: &InlineKeyboardMarkup, label: &str) -> CallbackQuery {
+    let data = keyboard
+        .inline_keyboard()
+        .iter()
+        .flatten()
+        .find(|button| button.text() == label)
+        .map(|button| match button.kind() {
+            InlineKeyboardButtonKind::CallbackData(data) => data.clone(),
+            kind => panic!("unexpected button kind: {:?}", kind),
+        })
+        .unwrap();
+
+    let bot = json!({"id": 1, "is_bot": true, "first_name": "Bot"});
+    serde_json::from_value(json!({
+        "id": "1",
+        "from": user(42),
+        "message": message_value(bot, label),
+        "chat_instance": "-1",
+        "data": data,
+    }))
+    .unwrap()
+}
+
+pub fn inline_query(user_id: i64, text: &str, offset: &str) -> InlineQuery {
+    serde_json::from_value(json!({
+        "id": "q1",
+        "from": user(user_id),
+        "query": text,
+        "offset": offset,
+    }))
+    .unwrap()
+}
+
+pub fn chosen_inline_result(user_id: i64, text: &str, result_id: &str) -> ChosenInlineResult {
+    serde_json::from_value(json!({
+        "result_id": result_id,
+        "from": user(user_id),
+        "query": text,
+    }))
+    .unwrap()
+}
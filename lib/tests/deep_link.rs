@@ -0,0 +1,70 @@
+mod common;
+
+use telegram_bot::util::deep_link::{decode_payload, encode_payload, DeepLinkError, DeepLinks};
+
+use common::message;
+
+#[test]
+fn links() {
+    let links = DeepLinks::new("@example_bot");
+    assert_eq!(links.username(), "example_bot");
+    assert_eq!(
+        links.start_group_raw("team_1").unwrap(),
+        "https://t.me/example_bot?startgroup=team_1"
+    );
+    assert!(matches!(
+        links.start_raw("with space"),
+        Err(DeepLinkError::InvalidCharacters)
+    ));
+    assert!(matches!(links.start_raw(""), Err(DeepLinkError::Empty)));
+    assert!(matches!(
+        links.start_raw(&"a".repeat(65)),
+        Err(DeepLinkError::TooLong(65))
+    ));
+    assert!(matches!(
+        links.start(&"x".repeat(60)),
+        Err(DeepLinkError::TooLong(_))
+    ));
+}
+
+#[test]
+fn payload_round_trip() {
+    let payload = encode_payload(&("ref", 42u32)).unwrap();
+    assert_eq!(payload, "A3JlZio");
+    assert_eq!(
+        decode_payload::<(String, u32)>(&payload).unwrap(),
+        ("ref".to_owned(), 42)
+    );
+
+    assert!(matches!(
+        decode_payload::<(String, u32)>("A3JlZg"),
+        Err(DeepLinkError::Malformed(_))
+    ));
+    assert!(matches!(
+        decode_payload::<(String, u32)>(""),
+        Err(DeepLinkError::Empty)
+    ));
+    assert!(matches!(
+        decode_payload::<(String, u32)>("A"),
+        Err(DeepLinkError::InvalidBase64)
+    ));
+}
+
+#[test]
+fn start_message() {
+    let links = DeepLinks::new("Example_Bot");
+    let payload = |text| links.start_payload(&message(text)).map(str::to_owned);
+    assert_eq!(payload("/start ref-42"), Some("ref-42".to_owned()));
+    assert_eq!(
+        payload("/start@example_bot ref-42"),
+        Some("ref-42".to_owned())
+    );
+    assert_eq!(payload("/start@other_bot ref-42"), None);
+    assert_eq!(payload("/start@ ref-42"), None);
+    assert_eq!(payload("/start"), None);
+    assert_eq!(payload("/started ref-42"), None);
+    assert_eq!(payload("hello"), None);
+
+    let decoded = links.decode_start_payload::<(String, u32)>(&message("/start A3JlZio"));
+    assert_eq!(decoded.unwrap().unwrap(), ("ref".to_owned(), 42));
+}
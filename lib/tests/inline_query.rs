@@ -0,0 +1,110 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use telegram_bot::util::inline_query::{InlineQueryAnswerer, MAX_INLINE_RESULTS};
+use telegram_bot::{
+    InlineQuery, InlineQueryResult, InlineQueryResultArticle, InputTextMessageContent,
+};
+
+use common::inline_query;
+
+fn article(i: usize) -> InlineQueryResult {
+    let content = InputTextMessageContent {
+        message_text: i.to_string(),
+        parse_mode: None,
+        disable_web_page_preview: false,
+    };
+    InlineQueryResultArticle::new(i.to_string(), i.to_string(), content).into()
+}
+
+fn serialized(answer: &impl serde::Serialize) -> serde_json::Value {
+    serde_json::to_value(answer).unwrap()
+}
+
+macro_rules! answerer {
+    ($calls:expr, $total:expr) => {{
+        let calls = $calls.clone();
+        InlineQueryAnswerer::new(move |_query: InlineQuery, offset: usize| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async move { (offset..$total).map(article).collect::<Vec<_>>() }
+        })
+    }};
+}
+
+#[tokio::test]
+async fn pages_with_next_offset() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let answerer = answerer!(calls, 120);
+
+    let first = serialized(&answerer.prepare(&inline_query(1, "cats", "")).await);
+    assert_eq!(
+        first["results"].as_array().unwrap().len(),
+        MAX_INLINE_RESULTS
+    );
+    assert_eq!(first["next_offset"], "50");
+    assert_eq!(first["cache_time"], 300);
+    assert_eq!(first["results"][0]["id"], "0");
+
+    let last = serialized(&answerer.prepare(&inline_query(1, "cats", "100")).await);
+    assert_eq!(last["results"].as_array().unwrap().len(), 20);
+    assert_eq!(last["next_offset"], "120");
+    assert_eq!(last["results"][0]["id"], "100");
+
+    let end = serialized(&answerer.prepare(&inline_query(1, "cats", "120")).await);
+    assert_eq!(end["results"].as_array().unwrap().len(), 0);
+    assert!(end.get("next_offset").is_none());
+}
+
+#[tokio::test]
+async fn bad_offset_ends_paging() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let answerer = answerer!(calls, 120);
+
+    let answer = serialized(&answerer.prepare(&inline_query(1, "cats", "garbage")).await);
+    assert_eq!(answer["results"].as_array().unwrap().len(), 0);
+    assert!(answer.get("next_offset").is_none());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn caches_by_query_and_offset() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let answerer = answerer!(calls, 10);
+
+    let first = answerer.prepare(&inline_query(1, "cats", "")).await;
+    let second = answerer.prepare(&inline_query(2, "cats", "")).await;
+    assert_eq!(first, second);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    answerer.prepare(&inline_query(1, "dogs", "")).await;
+    answerer.prepare(&inline_query(1, "cats", "5")).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn personal_results_are_cached_per_user() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut answerer = answerer!(calls, 10);
+    answerer.is_personal();
+
+    let answer = serialized(&answerer.prepare(&inline_query(1, "cats", "")).await);
+    assert_eq!(answer["is_personal"], true);
+    answerer.prepare(&inline_query(1, "cats", "")).await;
+    answerer.prepare(&inline_query(2, "cats", "")).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn zero_cache_time_disables_cache() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut answerer = answerer!(calls, 10);
+    answerer.cache_time(Duration::from_secs(0));
+
+    answerer.prepare(&inline_query(1, "cats", "")).await;
+    let answer = serialized(&answerer.prepare(&inline_query(1, "cats", "")).await);
+    assert_eq!(answer["cache_time"], 0);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,80 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use telegram_bot::util::login::{LoginError, LoginVerifier};
+use telegram_bot::UserId;
+
+const TOKEN: &str = "123456:ABC-DEF";
+const AUTH_DATE: u64 = 1625090000;
+
+fn params() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("id", "42"),
+        ("first_name", "Fedor"),
+        ("username", "knsd"),
+        ("photo_url", "https://t.me/i/userpic/320/knsd.jpg"),
+        ("auth_date", "1625090000"),
+        (
+            "hash",
+            "4e0acc96120dcc388c3b4ecf00c3b539409875c91928dd833c13578b1d54072f",
+        ),
+    ]
+}
+
+fn at(seconds: u64) -> std::time::SystemTime {
+    UNIX_EPOCH + Duration::from_secs(AUTH_DATE + seconds)
+}
+
+#[test]
+fn valid_data() {
+    let user = LoginVerifier::new(TOKEN)
+        .verify_at(params(), at(60))
+        .unwrap();
+    assert_eq!(user.id, UserId::new(42));
+    assert_eq!(user.first_name, "Fedor");
+    assert_eq!(user.last_name, None);
+    assert_eq!(user.username.as_deref(), Some("knsd"));
+}
+
+#[test]
+fn tampered_data() {
+    let mut params = params();
+    params[0].1 = "43";
+    assert_eq!(
+        LoginVerifier::new(TOKEN).verify_at(params, at(60)),
+        Err(LoginError::InvalidHash)
+    );
+
+    assert_eq!(
+        LoginVerifier::new("654321:other").verify_at(self::params(), at(60)),
+        Err(LoginError::InvalidHash)
+    );
+}
+
+#[test]
+fn missing_hash() {
+    let mut params = params();
+    params.pop();
+    assert_eq!(
+        LoginVerifier::new(TOKEN).verify_at(params, at(60)),
+        Err(LoginError::MissingField("hash"))
+    );
+}
+
+#[test]
+fn expired_data() {
+    let day = 24 * 60 * 60;
+    let mut verifier = LoginVerifier::new(TOKEN);
+    assert_eq!(
+        verifier.verify_at(params(), at(day + 1)),
+        Err(LoginError::Expired)
+    );
+
+    verifier.max_age(Some(Duration::from_secs(30)));
+    assert_eq!(
+        verifier.verify_at(params(), at(31)),
+        Err(LoginError::Expired)
+    );
+
+    verifier.max_age(None);
+    assert!(verifier.verify_at(params(), at(365 * day)).is_ok());
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use telegram_bot::prelude::*;
+use telegram_bot::util::menu::{Menu, MenuNode};
+use telegram_bot::{CallbackDataError, InlineKeyboardMarkup};
+
+use common::{key, press};
+
+#[derive(Debug, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+}
+
+fn menu() -> Menu<Frequency> {
+    let mut root = MenuNode::root("Settings");
+    root.add_submenu("Notifications", "Notification settings")
+        .add_submenu("Frequency", "How often?")
+        .add_leaf("Daily", |_| Frequency::Daily)
+        .add_leaf("Weekly", |_| Frequency::Weekly);
+    root.add_submenu("Language", "Choose a language");
+    Menu::new("settings", &key(), root).unwrap()
+}
+
+fn labels(keyboard: &InlineKeyboardMarkup) -> Vec<Vec<&str>> {
+    keyboard
+        .inline_keyboard()
+        .iter()
+        .map(|row| row.iter().map(|button| button.text()).collect())
+        .collect()
+}
+
+#[test]
+fn keyboards() {
+    let menu = menu();
+    assert_eq!(
+        labels(&menu.keyboard(&[])),
+        vec![vec!["Notifications"], vec!["Language"]]
+    );
+    assert_eq!(
+        labels(&menu.keyboard(&[0])),
+        vec![vec!["Frequency"], vec!["« Back"]]
+    );
+    assert_eq!(
+        labels(&menu.keyboard(&[0, 0])),
+        vec![vec!["Daily"], vec!["Weekly"], vec!["« Back", "« Home"]]
+    );
+    assert!(labels(&menu.keyboard(&[5])).is_empty());
+}
+
+#[test]
+fn navigation_edits_message() {
+    let menu = menu();
+
+    let query = press(&menu.keyboard(&[]), "Notifications");
+    let update = menu.handle(&query).unwrap();
+    assert!(update.result.is_none());
+    let mut expected = query.message.as_ref().unwrap().edit_text("");
+    expected
+        .formatted_text("Notification settings")
+        .reply_markup(menu.keyboard(&[0]));
+    assert_eq!(update.edit.unwrap(), expected);
+
+    let query = press(&menu.keyboard(&[0, 0]), "« Home");
+    let mut expected = query.message.as_ref().unwrap().edit_text("");
+    expected
+        .formatted_text("Settings")
+        .reply_markup(menu.keyboard(&[]));
+    assert_eq!(menu.handle(&query).unwrap().edit.unwrap(), expected);
+
+    let query = press(&menu.keyboard(&[0, 0]), "« Back");
+    let mut expected = query.message.as_ref().unwrap().edit_text("");
+    expected
+        .formatted_text("Notification settings")
+        .reply_markup(menu.keyboard(&[0]));
+    assert_eq!(menu.handle(&query).unwrap().edit.unwrap(), expected);
+}
+
+#[test]
+fn leaf_calls_callback() {
+    let menu = menu();
+    let query = press(&menu.keyboard(&[0, 0]), "Weekly");
+    let update = menu.handle(&query).unwrap();
+    assert!(update.edit.is_none());
+    assert_eq!(update.result, Some(Frequency::Weekly));
+}
+
+#[test]
+fn foreign_data_is_ignored() {
+    let mut root = MenuNode::root("Other");
+    root.add_leaf("Daily", |_| Frequency::Daily);
+    let other = Menu::new("other", &key(), root).unwrap();
+
+    let query = press(&menu().keyboard(&[0, 0]), "Daily");
+    assert!(other.handle(&query).is_none());
+}
+
+#[test]
+fn deep_menu_is_rejected() {
+    let mut root = MenuNode::<Frequency>::root("Root");
+    let mut node = &mut root;
+    for level in 0..40 {
+        node = node.add_submenu(format!("Level {}", level), "Deeper");
+    }
+    assert!(matches!(
+        Menu::new("deep", &key(), root),
+        Err(CallbackDataError::TooLong(_))
+    ));
+}
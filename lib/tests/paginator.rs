@@ -0,0 +1,106 @@
+mod common;
+
+use telegram_bot::prelude::*;
+use telegram_bot::util::paginator::Paginator;
+use telegram_bot::{
+    CallbackDataError, CallbackDataKey, InlineKeyboardButton, InlineKeyboardMarkup,
+};
+
+use common::{key, press};
+
+fn paginator() -> Paginator<u32> {
+    let mut paginator = Paginator::new("nums", &key(), (1..=25).collect()).unwrap();
+    paginator.page_size(10);
+    paginator
+}
+
+fn button(item: &u32) -> InlineKeyboardButton {
+    InlineKeyboardButton::callback(item.to_string(), item.to_string())
+}
+
+#[test]
+fn pages() {
+    let paginator = paginator();
+    assert_eq!(paginator.pages(), 3);
+    assert_eq!(paginator.items(2), &[21, 22, 23, 24, 25]);
+    assert_eq!(paginator.items(100), &[21, 22, 23, 24, 25]);
+
+    let empty = Paginator::<u32>::new("empty", &key(), Vec::new()).unwrap();
+    assert_eq!(empty.pages(), 1);
+    assert!(empty.items(0).is_empty());
+    assert!(empty.navigation(0).is_empty());
+}
+
+#[test]
+fn navigation() {
+    let paginator = paginator();
+    let labels = |page| {
+        paginator
+            .navigation(page)
+            .iter()
+            .map(|button| button.text().to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(labels(0), vec!["1/3", "▶"]);
+    assert_eq!(labels(1), vec!["◀", "2/3", "▶"]);
+    assert_eq!(labels(2), vec!["◀", "3/3"]);
+}
+
+#[test]
+fn next_page_edits_keyboard() {
+    let paginator = paginator();
+    let keyboard = paginator.keyboard(0, button);
+    let query = press(&keyboard, "▶");
+
+    assert_eq!(paginator.requested_page(&query), Some(Some(1)));
+    let update = paginator.update_keyboard(&query, button).unwrap();
+    let edit = update.edit.unwrap();
+    let expected = {
+        let mut expected = InlineKeyboardMarkup::new();
+        for i in 11..=20 {
+            expected.add_row(vec![button(&i)]);
+        }
+        expected.add_row(paginator.navigation(1));
+        expected
+    };
+    assert_eq!(
+        edit,
+        query.message.unwrap().edit_reply_markup(Some(expected))
+    );
+}
+
+#[test]
+fn counter_only_answers() {
+    let paginator = paginator();
+    let query = press(&paginator.keyboard(1, button), "2/3");
+
+    assert_eq!(paginator.requested_page(&query), Some(None));
+    let update = paginator
+        .update_text(&query, |items| format!("{:?}", items))
+        .unwrap();
+    assert!(update.edit.is_none());
+}
+
+#[test]
+fn foreign_data_is_ignored() {
+    let other = Paginator::new("other", &key(), vec![1u32, 2, 3]).unwrap();
+    let query = press(&paginator().keyboard(0, button), "▶");
+    assert!(other.requested_page(&query).is_none());
+
+    let other_key = CallbackDataKey::new("654321:other-token");
+    let other = Paginator::new("nums", &other_key, (1..=25).collect::<Vec<u32>>()).unwrap();
+    assert!(other.requested_page(&query).is_none());
+
+    let query = press(&paginator().keyboard(0, button), "1");
+    assert!(paginator().requested_page(&query).is_none());
+}
+
+#[test]
+fn long_id_is_rejected() {
+    let id = "x".repeat(30);
+    assert!(matches!(
+        Paginator::new(id, &key(), vec![1u32]),
+        Err(CallbackDataError::TooLong(_))
+    ));
+    assert!(Paginator::new("x".repeat(20), &key(), vec![1u32]).is_ok());
+}
@@ -0,0 +1,56 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use telegram_bot::util::login::LoginError;
+use telegram_bot::util::web_app::WebAppValidator;
+use telegram_bot::UserId;
+
+const TOKEN: &str = "123456:ABC-DEF";
+
+const INIT_DATA: &str = "query_id=AAHdF6IQAAAAAN0XohDhrOrc&user=%7B%22id%22%3A42%2C%22first_name\
+    %22%3A%22Fedor%22%2C%22username%22%3A%22knsd%22%2C%22language_code%22%3A%22en%22%7D&\
+    auth_date=1625090000&start_param=promo&\
+    hash=d5d074c809dfca1343c47bba6772bcf091ed59230211dfb834073d27a5ae674b";
+
+fn at(seconds: u64) -> std::time::SystemTime {
+    UNIX_EPOCH + Duration::from_secs(1625090000 + seconds)
+}
+
+#[test]
+fn valid_init_data() {
+    let data = WebAppValidator::new(TOKEN)
+        .validate_at(INIT_DATA, at(60))
+        .unwrap();
+    assert_eq!(data.query_id.as_deref(), Some("AAHdF6IQAAAAAN0XohDhrOrc"));
+    assert_eq!(data.start_param.as_deref(), Some("promo"));
+    assert_eq!(data.auth_date, 1625090000);
+
+    let user = data.user.unwrap();
+    assert_eq!(user.id, UserId::new(42));
+    assert_eq!(user.username.as_deref(), Some("knsd"));
+    assert_eq!(user.language_code.as_deref(), Some("en"));
+    assert!(data.receiver.is_none());
+}
+
+#[test]
+fn tampered_init_data() {
+    let init_data = INIT_DATA.replace("promo", "other");
+    assert_eq!(
+        WebAppValidator::new(TOKEN).validate_at(&init_data, at(60)),
+        Err(LoginError::InvalidHash)
+    );
+
+    assert_eq!(
+        WebAppValidator::new("654321:other").validate_at(INIT_DATA, at(60)),
+        Err(LoginError::InvalidHash)
+    );
+}
+
+#[test]
+fn expired_init_data() {
+    let mut validator = WebAppValidator::new(TOKEN);
+    validator.max_age(Some(Duration::from_secs(30)));
+    assert_eq!(
+        validator.validate_at(INIT_DATA, at(31)),
+        Err(LoginError::Expired)
+    );
+}
@@ -0,0 +1,210 @@
+use crate::formatting::render::{render_html, render_markdown_v2};
+use crate::types::*;
+
+/// Text with formatting entities.
+///
+/// Pieces of text are appended with the builder methods and can be nested,
+/// the result is sent either as plain text with `entities`, or rendered as
+/// escaped `ParseMode::Html` or `ParseMode::MarkdownV2` markup.
+///
+/// ```rust
+/// # use telegram_bot_raw::FormattedText;
+/// let mut text = FormattedText::new();
+/// text.bold("Warning: ")
+///     .text("1 + 1 = 2. ")
+///     .italic(FormattedText::new().text("see ").link("docs", "https://example.com"));
+///
+/// assert_eq!(
+///     text.to_html(),
+///     "<b>Warning: </b>1 + 1 = 2. <i>see <a href=\"https://example.com\">docs</a></i>"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+pub struct FormattedText {
+    text: String,
+    entities: Vec<MessageEntity>,
+}
+
+impl FormattedText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create from plain text and entities with offsets in UTF-16 code units.
+    pub fn from_parts(text: String, entities: Vec<MessageEntity>) -> Self {
+        Self { text, entities }
+    }
+
+    /// Plain text of the message.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Entities of the message, with offsets in UTF-16 code units.
+    pub fn entities(&self) -> &[MessageEntity] {
+        &self.entities
+    }
+
+    /// Split into plain text and entities.
+    pub fn into_parts(self) -> (String, Vec<MessageEntity>) {
+        (self.text, self.entities)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Render as `ParseMode::Html` markup.
+    pub fn to_html(&self) -> String {
+        render_html(&self.text, &self.entities)
+    }
+
+    /// Render as `ParseMode::MarkdownV2` markup.
+    pub fn to_markdown_v2(&self) -> String {
+        render_markdown_v2(&self.text, &self.entities)
+    }
+
+    /// Append text without any formatting applied to it.
+    /// Entities of an appended `FormattedText` are preserved.
+    pub fn text<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let text = text.into();
+        let offset = utf16_len(&self.text);
+        self.text.push_str(&text.text);
+        self.entities
+            .extend(text.entities.into_iter().map(|mut entity| {
+                entity.offset += offset;
+                entity
+            }));
+        self
+    }
+
+    /// Append text wrapped into an entity of the given kind.
+    pub fn entity<T>(&mut self, kind: MessageEntityKind, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let text = text.into();
+        let offset = utf16_len(&self.text);
+        let length = utf16_len(&text.text);
+        if length > 0 {
+            self.entities.push(MessageEntity::new(kind, offset, length));
+        }
+        self.text(text)
+    }
+
+    pub fn bold<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        self.entity(MessageEntityKind::Bold, text)
+    }
+
+    pub fn italic<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        self.entity(MessageEntityKind::Italic, text)
+    }
+
+    pub fn underline<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        self.entity(MessageEntityKind::Underline, text)
+    }
+
+    pub fn strikethrough<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        self.entity(MessageEntityKind::Strikethrough, text)
+    }
+
+    pub fn spoiler<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        self.entity(MessageEntityKind::Spoiler, text)
+    }
+
+    /// Monowidth string. Code can't contain other entities.
+    pub fn code<T>(&mut self, text: T) -> &mut Self
+    where
+        T: AsRef<str>,
+    {
+        self.entity(MessageEntityKind::Code, text.as_ref())
+    }
+
+    /// Monowidth block. Code can't contain other entities.
+    pub fn pre<T>(&mut self, text: T) -> &mut Self
+    where
+        T: AsRef<str>,
+    {
+        self.entity(MessageEntityKind::Pre(None), text.as_ref())
+    }
+
+    /// Monowidth block with the programming language of the code.
+    pub fn pre_with_language<T, L>(&mut self, text: T, language: L) -> &mut Self
+    where
+        T: AsRef<str>,
+        L: Into<String>,
+    {
+        self.entity(MessageEntityKind::Pre(Some(language.into())), text.as_ref())
+    }
+
+    /// Clickable text which opens `url`.
+    pub fn link<T, U>(&mut self, text: T, url: U) -> &mut Self
+    where
+        T: Into<FormattedText>,
+        U: Into<String>,
+    {
+        self.entity(MessageEntityKind::TextLink(url.into()), text)
+    }
+
+    /// Mention of a user, works for users without usernames.
+    pub fn text_mention<T>(&mut self, text: T, user: &User) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        self.entity(MessageEntityKind::TextMention(user.clone()), text)
+    }
+}
+
+impl<'a> From<&'a str> for FormattedText {
+    fn from(value: &'a str) -> Self {
+        FormattedText {
+            text: value.to_owned(),
+            entities: Vec::new(),
+        }
+    }
+}
+
+impl From<String> for FormattedText {
+    fn from(value: String) -> Self {
+        FormattedText {
+            text: value,
+            entities: Vec::new(),
+        }
+    }
+}
+
+impl<'a> From<&'a String> for FormattedText {
+    fn from(value: &'a String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl<'a> From<&'a FormattedText> for FormattedText {
+    fn from(value: &'a FormattedText) -> Self {
+        value.clone()
+    }
+}
+
+impl<'a> From<&'a mut FormattedText> for FormattedText {
+    fn from(value: &'a mut FormattedText) -> Self {
+        value.clone()
+    }
+}
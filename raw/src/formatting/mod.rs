@@ -0,0 +1,11 @@
+//! Conversion between plain text with entities and formatted markup.
+
+pub mod builder;
+pub mod parse;
+pub mod render;
+pub mod split;
+
+pub use self::builder::*;
+pub use self::parse::*;
+pub use self::render::*;
+pub use self::split::*;
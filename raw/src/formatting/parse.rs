@@ -0,0 +1,457 @@
+use std::error;
+use std::fmt;
+
+use crate::formatting::builder::FormattedText;
+use crate::types::*;
+
+/// Parse `ParseMode::Html` markup into plain text with entities.
+///
+/// Mentions by `tg://user?id=` links are parsed as `TextLink` entities,
+/// because the mentioned `User` is not known.
+pub fn parse_html(markup: &str) -> Result<FormattedText, MarkupError> {
+    let mut out = Output::default();
+    let mut stack: Vec<(String, Option<MessageEntityKind>, Integer)> = Vec::new();
+    let mut rest = markup;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '<' => {
+                let end = rest
+                    .find('>')
+                    .ok_or_else(|| MarkupError::Unclosed(rest.to_owned()))?;
+                let tag = &rest[1..end];
+                rest = &rest[end + 1..];
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    let name = name.trim().to_lowercase();
+                    match stack.pop() {
+                        Some((open, kind, start)) if open == name => out.close(kind, start),
+                        _ => return Err(MarkupError::UnmatchedTag(name)),
+                    }
+                    continue;
+                }
+
+                let (name, attributes) = match tag.find(char::is_whitespace) {
+                    Some(index) => (&tag[..index], &tag[index..]),
+                    None => (tag, ""),
+                };
+                let name = name.to_lowercase();
+                let in_pre = match stack.last() {
+                    Some((open, Some(MessageEntityKind::Pre(None)), start)) => {
+                        open == "pre" && *start == out.offset
+                    }
+                    _ => false,
+                };
+                let kind = match name.as_str() {
+                    "b" | "strong" => Some(MessageEntityKind::Bold),
+                    "i" | "em" => Some(MessageEntityKind::Italic),
+                    "u" | "ins" => Some(MessageEntityKind::Underline),
+                    "s" | "strike" | "del" => Some(MessageEntityKind::Strikethrough),
+                    "tg-spoiler" => Some(MessageEntityKind::Spoiler),
+                    "span"
+                        if html_attribute(attributes, "class").as_deref() == Some("tg-spoiler") =>
+                    {
+                        Some(MessageEntityKind::Spoiler)
+                    }
+                    "code" if in_pre => {
+                        // `<pre><code class="language-rust">` sets the language of the block.
+                        let language = html_attribute(attributes, "class")
+                            .and_then(|class| class.strip_prefix("language-").map(str::to_owned));
+                        if let Some(language) = language {
+                            stack.last_mut().unwrap().1 =
+                                Some(MessageEntityKind::Pre(Some(language)));
+                        }
+                        None
+                    }
+                    "code" => Some(MessageEntityKind::Code),
+                    "pre" => Some(MessageEntityKind::Pre(None)),
+                    "a" => html_attribute(attributes, "href").map(MessageEntityKind::TextLink),
+                    "tg-emoji" => {
+                        html_attribute(attributes, "emoji-id").map(MessageEntityKind::CustomEmoji)
+                    }
+                    _ => return Err(MarkupError::UnsupportedTag(name)),
+                };
+                stack.push((name, kind, out.offset));
+            }
+            '&' => {
+                let (decoded, len) = html_reference(rest).unwrap_or(('&', 1));
+                out.push(decoded);
+                rest = &rest[len..];
+            }
+            c => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    match stack.pop() {
+        Some((name, _, _)) => Err(MarkupError::Unclosed(name)),
+        None => Ok(out.finish()),
+    }
+}
+
+/// Parse `ParseMode::MarkdownV2` markup into plain text with entities.
+///
+/// Mentions by `tg://user?id=` links are parsed as `TextLink` entities,
+/// because the mentioned `User` is not known.
+pub fn parse_markdown_v2(markup: &str) -> Result<FormattedText, MarkupError> {
+    let mut out = Output::default();
+    // Open markers with the offsets where their entities start.
+    let mut stack: Vec<(&'static str, Integer)> = Vec::new();
+    let mut rest = markup;
+
+    while let Some(c) = rest.chars().next() {
+        let marker = ["```", "__", "||", "![", "*", "_", "~", "`", "[", "]"]
+            .iter()
+            .find(|marker| rest.starts_with(**marker))
+            .copied();
+
+        match (c, marker) {
+            ('\\', _) => {
+                let escaped = rest[1..].chars().next().filter(|&c| (c as u32) < 127);
+                match escaped {
+                    Some(escaped) => {
+                        out.push(escaped);
+                        rest = &rest[1 + escaped.len_utf8()..];
+                    }
+                    None => {
+                        out.push('\\');
+                        rest = &rest[1..];
+                    }
+                }
+            }
+            // `\r` separates adjacent italic and underline markers, e.g. `_\r__`.
+            ('\r', _)
+                if markup[..markup.len() - rest.len()].ends_with('_')
+                    && rest[1..].starts_with('_') =>
+            {
+                rest = &rest[1..];
+            }
+            (_, Some("```")) => {
+                let (pre, len) = markdown_v2_pre(&rest[3..])?;
+                let start = out.offset;
+                out.push_str(&pre.text);
+                out.close(Some(MessageEntityKind::Pre(pre.language)), start);
+                rest = &rest[3 + len..];
+            }
+            (_, Some("`")) => {
+                let (code, len) = markdown_v2_code(&rest[1..], "`")?;
+                let start = out.offset;
+                out.push_str(&code);
+                out.close(Some(MessageEntityKind::Code), start);
+                rest = &rest[1 + len..];
+            }
+            (_, Some(marker @ "[")) | (_, Some(marker @ "![")) => {
+                stack.push((marker, out.offset));
+                rest = &rest[marker.len()..];
+            }
+            (_, Some("]")) => {
+                let position = stack
+                    .iter()
+                    .rposition(|(marker, _)| *marker == "[" || *marker == "![")
+                    .ok_or(MarkupError::UnescapedCharacter(']'))?;
+                let (marker, start) = stack.remove(position);
+                if !rest[1..].starts_with('(') {
+                    return Err(MarkupError::UnescapedCharacter(']'));
+                }
+                let (url, len) = markdown_v2_code(&rest[2..], ")")?;
+                let kind = match (marker, url.strip_prefix("tg://emoji?id=")) {
+                    ("![", Some(id)) => MessageEntityKind::CustomEmoji(id.to_owned()),
+                    ("![", None) => return Err(MarkupError::UnescapedCharacter('!')),
+                    _ => MessageEntityKind::TextLink(url),
+                };
+                out.close(Some(kind), start);
+                rest = &rest[2 + len..];
+            }
+            (_, Some(marker)) => {
+                match stack.iter().rposition(|(open, _)| *open == marker) {
+                    Some(position) => {
+                        let (_, start) = stack.remove(position);
+                        out.close(Some(markdown_v2_kind(marker)), start);
+                    }
+                    None => stack.push((marker, out.offset)),
+                }
+                rest = &rest[marker.len()..];
+            }
+            (c, None) if "()>#+-=|{}.!".contains(c) => {
+                return Err(MarkupError::UnescapedCharacter(c));
+            }
+            (c, None) => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    match stack.pop() {
+        Some((marker, _)) => Err(MarkupError::Unclosed(marker.to_owned())),
+        None => Ok(out.finish()),
+    }
+}
+
+/// Parse legacy `ParseMode::Markdown` markup into plain text with entities.
+pub fn parse_markdown(markup: &str) -> Result<FormattedText, MarkupError> {
+    let mut out = Output::default();
+    let mut rest = markup;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\\' if rest[1..].starts_with(|c: char| "_*`[".contains(c)) => {
+                out.push(rest[1..].chars().next().unwrap());
+                rest = &rest[2..];
+            }
+            '*' | '_' | '`' | '[' => {
+                let (kind, content, len) = if let Some(after) = rest.strip_prefix("```") {
+                    let end = after
+                        .find("```")
+                        .ok_or_else(|| MarkupError::Unclosed("```".to_owned()))?;
+                    let pre = split_pre_language(&after[..end]);
+                    (MessageEntityKind::Pre(pre.language), pre.text, end + 6)
+                } else if c == '[' {
+                    let end = rest
+                        .find("](")
+                        .ok_or_else(|| MarkupError::Unclosed("[".to_owned()))?;
+                    let url_end = rest[end..]
+                        .find(')')
+                        .ok_or_else(|| MarkupError::Unclosed("(".to_owned()))?;
+                    let url = rest[end + 2..end + url_end].to_owned();
+                    (
+                        MessageEntityKind::TextLink(url),
+                        rest[1..end].to_owned(),
+                        end + url_end + 1,
+                    )
+                } else {
+                    let end = rest[1..]
+                        .find(c)
+                        .ok_or_else(|| MarkupError::Unclosed(c.to_string()))?;
+                    let kind = match c {
+                        '*' => MessageEntityKind::Bold,
+                        '_' => MessageEntityKind::Italic,
+                        _ => MessageEntityKind::Code,
+                    };
+                    (kind, rest[1..1 + end].to_owned(), end + 2)
+                };
+                let start = out.offset;
+                out.push_str(&content);
+                out.close(Some(kind), start);
+                rest = &rest[len..];
+            }
+            c => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    Ok(out.finish())
+}
+
+/// Parse `markup` using `parse_mode`.
+pub fn parse_with_parse_mode(
+    markup: &str,
+    parse_mode: ParseMode,
+) -> Result<FormattedText, MarkupError> {
+    match parse_mode {
+        ParseMode::Html => parse_html(markup),
+        ParseMode::MarkdownV2 => parse_markdown_v2(markup),
+        ParseMode::Markdown => parse_markdown(markup),
+    }
+}
+
+/// Error of parsing formatted markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkupError {
+    /// Tag is not supported by Telegram.
+    UnsupportedTag(String),
+    /// Closing tag doesn't match the last opened tag.
+    UnmatchedTag(String),
+    /// Tag or entity is not closed.
+    Unclosed(String),
+    /// Reserved character must be escaped.
+    UnescapedCharacter(char),
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkupError::UnsupportedTag(tag) => write!(f, "unsupported tag <{}>", tag),
+            MarkupError::UnmatchedTag(tag) => write!(f, "unmatched closing tag </{}>", tag),
+            MarkupError::Unclosed(markup) => write!(f, "unclosed {:?}", markup),
+            MarkupError::UnescapedCharacter(c) => {
+                write!(f, "character {:?} is reserved and must be escaped", c)
+            }
+        }
+    }
+}
+
+impl error::Error for MarkupError {}
+
+/// Plain text with entities accumulated by the parsers.
+#[derive(Default)]
+struct Output {
+    text: String,
+    entities: Vec<MessageEntity>,
+    offset: Integer,
+}
+
+impl Output {
+    fn push(&mut self, c: char) {
+        self.text.push(c);
+        self.offset += c.len_utf16() as Integer;
+    }
+
+    fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.offset += utf16_len(text);
+    }
+
+    /// Add an entity of `kind` from `start` to the current offset.
+    fn close(&mut self, kind: Option<MessageEntityKind>, start: Integer) {
+        if let Some(kind) = kind {
+            if self.offset > start {
+                self.entities
+                    .push(MessageEntity::new(kind, start, self.offset - start));
+            }
+        }
+    }
+
+    fn finish(mut self) -> FormattedText {
+        // Outer entities go first.
+        self.entities
+            .sort_by(|a, b| a.offset.cmp(&b.offset).then(b.length.cmp(&a.length)));
+        FormattedText::from_parts(self.text, self.entities)
+    }
+}
+
+/// Decode a character reference at the start of `text`,
+/// returns the character and the length of the reference.
+fn html_reference(text: &str) -> Option<(char, usize)> {
+    let end = text.find(';').filter(|&end| end <= 10)?;
+    let name = &text[1..end];
+    let decoded = match name {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(|c| c == 'x' || c == 'X') {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            std::char::from_u32(code)?
+        }
+    };
+    Some((decoded, end + 1))
+}
+
+fn html_unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let (decoded, len) = match c {
+            '&' => html_reference(rest).unwrap_or(('&', 1)),
+            c => (c, c.len_utf8()),
+        };
+        unescaped.push(decoded);
+        rest = &rest[len..];
+    }
+    unescaped
+}
+
+/// Value of the attribute `name` of a tag, with character references decoded.
+fn html_attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes.trim_start();
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let attribute = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+
+        let mut value = None;
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (raw, remaining) = match after.chars().next() {
+                Some(quote @ '"') | Some(quote @ '\'') => {
+                    let end = after[1..].find(quote)? + 1;
+                    (&after[1..end], &after[end + 1..])
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            value = Some(raw);
+            rest = remaining.trim_start();
+        }
+
+        if attribute.eq_ignore_ascii_case(name) {
+            return Some(html_unescape(value.unwrap_or("")));
+        }
+    }
+    None
+}
+
+fn markdown_v2_kind(marker: &str) -> MessageEntityKind {
+    match marker {
+        "*" => MessageEntityKind::Bold,
+        "_" => MessageEntityKind::Italic,
+        "__" => MessageEntityKind::Underline,
+        "~" => MessageEntityKind::Strikethrough,
+        _ => MessageEntityKind::Spoiler,
+    }
+}
+
+/// Read text up to the unescaped `end`, where only `end` and `\` can be escaped.
+/// Returns the unescaped text and the length of the markup including `end`.
+fn markdown_v2_code(text: &str, end: &str) -> Result<(String, usize), MarkupError> {
+    let mut unescaped = String::new();
+    let mut rest = text;
+    loop {
+        if rest.starts_with(end) {
+            return Ok((unescaped, text.len() - rest.len() + end.len()));
+        }
+        let mut chars = rest.chars();
+        match chars.next() {
+            Some('\\') => {
+                let escaped = chars.next().unwrap_or('\\');
+                unescaped.push(escaped);
+                rest = &rest[(1 + escaped.len_utf8()).min(rest.len())..];
+            }
+            Some(c) => {
+                unescaped.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+            None => return Err(MarkupError::Unclosed(end.to_owned())),
+        }
+    }
+}
+
+struct Pre {
+    text: String,
+    language: Option<String>,
+}
+
+fn markdown_v2_pre(text: &str) -> Result<(Pre, usize), MarkupError> {
+    let (content, len) = markdown_v2_code(text, "```")?;
+    Ok((split_pre_language(&content), len))
+}
+
+/// Split the language on the first line of a code block from the code.
+/// The line breaks around the code are not part of it.
+fn split_pre_language(content: &str) -> Pre {
+    let (language, code) = match content.find('\n') {
+        Some(index) if !content[..index].contains(char::is_whitespace) => {
+            (&content[..index], &content[index + 1..])
+        }
+        _ => ("", content),
+    };
+    Pre {
+        text: code.strip_suffix('\n').unwrap_or(code).to_owned(),
+        language: Some(language)
+            .filter(|language| !language.is_empty())
+            .map(str::to_owned),
+    }
+}
@@ -0,0 +1,284 @@
+use crate::types::*;
+
+/// Escape text for `ParseMode::Html`.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape text for `ParseMode::MarkdownV2`.
+pub fn escape_markdown_v2(text: &str) -> String {
+    escape_chars(text, "_*[]()~`>#+-=|{}.!\\")
+}
+
+/// Escape text placed inside `pre` and `code` entities for `ParseMode::MarkdownV2`.
+pub fn escape_markdown_v2_code(text: &str) -> String {
+    escape_chars(text, "`\\")
+}
+
+/// Escape the url part of an inline link for `ParseMode::MarkdownV2`.
+pub fn escape_markdown_v2_url(text: &str) -> String {
+    escape_chars(text, ")\\")
+}
+
+fn escape_chars(text: &str, special: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if special.contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Render `text` with `entities` as `ParseMode::Html` markup.
+///
+/// Entities which are detected by Telegram automatically (mentions, hashtags, urls, etc.)
+/// are rendered as plain text. Entities that are out of bounds of `text` are ignored.
+pub fn render_html(text: &str, entities: &[MessageEntity]) -> String {
+    render(text, entities, &Html)
+}
+
+/// Render `text` with `entities` as `ParseMode::MarkdownV2` markup.
+///
+/// Entities which are detected by Telegram automatically (mentions, hashtags, urls, etc.)
+/// are rendered as plain text. Entities that are out of bounds of `text` are ignored.
+pub fn render_markdown_v2(text: &str, entities: &[MessageEntity]) -> String {
+    render(text, entities, &MarkdownV2)
+}
+
+/// Render `text` with `entities` using `parse_mode` markup.
+/// Returns `None` for the legacy `ParseMode::Markdown`, which can't express all entities.
+pub fn render_with_parse_mode(
+    text: &str,
+    entities: &[MessageEntity],
+    parse_mode: ParseMode,
+) -> Option<String> {
+    match parse_mode {
+        ParseMode::Html => Some(render_html(text, entities)),
+        ParseMode::MarkdownV2 => Some(render_markdown_v2(text, entities)),
+        ParseMode::Markdown => None,
+    }
+}
+
+trait Markup {
+    fn escape(&self, text: &str, code: bool) -> String;
+    fn open(&self, kind: &MessageEntityKind, out: &mut String);
+    fn close(&self, kind: &MessageEntityKind, out: &mut String);
+}
+
+struct Html;
+
+impl Markup for Html {
+    fn escape(&self, text: &str, _code: bool) -> String {
+        escape_html(text)
+    }
+
+    fn open(&self, kind: &MessageEntityKind, out: &mut String) {
+        use crate::types::MessageEntityKind::*;
+
+        match *kind {
+            Bold => out.push_str("<b>"),
+            Italic => out.push_str("<i>"),
+            Underline => out.push_str("<u>"),
+            Strikethrough => out.push_str("<s>"),
+            Spoiler => out.push_str("<tg-spoiler>"),
+            Code => out.push_str("<code>"),
+            Pre(None) => out.push_str("<pre>"),
+            Pre(Some(ref language)) => {
+                out.push_str("<pre><code class=\"language-");
+                out.push_str(&escape_html(language));
+                out.push_str("\">");
+            }
+            TextLink(ref url) => {
+                out.push_str("<a href=\"");
+                out.push_str(&escape_html(url));
+                out.push_str("\">");
+            }
+            TextMention(ref user) => {
+                out.push_str(&format!("<a href=\"tg://user?id={}\">", user.id));
+            }
+            CustomEmoji(ref id) => {
+                out.push_str("<tg-emoji emoji-id=\"");
+                out.push_str(&escape_html(id));
+                out.push_str("\">");
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&self, kind: &MessageEntityKind, out: &mut String) {
+        use crate::types::MessageEntityKind::*;
+
+        match *kind {
+            Bold => out.push_str("</b>"),
+            Italic => out.push_str("</i>"),
+            Underline => out.push_str("</u>"),
+            Strikethrough => out.push_str("</s>"),
+            Spoiler => out.push_str("</tg-spoiler>"),
+            Code => out.push_str("</code>"),
+            Pre(None) => out.push_str("</pre>"),
+            Pre(Some(_)) => out.push_str("</code></pre>"),
+            TextLink(_) | TextMention(_) => out.push_str("</a>"),
+            CustomEmoji(_) => out.push_str("</tg-emoji>"),
+            _ => {}
+        }
+    }
+}
+
+struct MarkdownV2;
+
+impl MarkdownV2 {
+    fn push_marker(out: &mut String, marker: &str) {
+        // `__` is greedily treated as underline, so adjacent italic and underline
+        // markers are separated by `\r`, which is ignored by Telegram.
+        if marker.starts_with('_') && out.ends_with('_') && !out.ends_with("\\_") {
+            out.push('\r');
+        }
+        out.push_str(marker);
+    }
+}
+
+impl Markup for MarkdownV2 {
+    fn escape(&self, text: &str, code: bool) -> String {
+        if code {
+            escape_markdown_v2_code(text)
+        } else {
+            escape_markdown_v2(text)
+        }
+    }
+
+    fn open(&self, kind: &MessageEntityKind, out: &mut String) {
+        use crate::types::MessageEntityKind::*;
+
+        match *kind {
+            Bold => Self::push_marker(out, "*"),
+            Italic => Self::push_marker(out, "_"),
+            Underline => Self::push_marker(out, "__"),
+            Strikethrough => Self::push_marker(out, "~"),
+            Spoiler => Self::push_marker(out, "||"),
+            Code => Self::push_marker(out, "`"),
+            Pre(ref language) => {
+                out.push_str("```");
+                if let Some(ref language) = *language {
+                    out.push_str(&escape_markdown_v2_code(language));
+                }
+                out.push('\n');
+            }
+            TextLink(_) | TextMention(_) => Self::push_marker(out, "["),
+            CustomEmoji(_) => Self::push_marker(out, "!["),
+            _ => {}
+        }
+    }
+
+    fn close(&self, kind: &MessageEntityKind, out: &mut String) {
+        use crate::types::MessageEntityKind::*;
+
+        match *kind {
+            Bold => Self::push_marker(out, "*"),
+            Italic => Self::push_marker(out, "_"),
+            Underline => Self::push_marker(out, "__"),
+            Strikethrough => Self::push_marker(out, "~"),
+            Spoiler => Self::push_marker(out, "||"),
+            Code => Self::push_marker(out, "`"),
+            Pre(_) => out.push_str("\n```"),
+            TextLink(ref url) => {
+                out.push_str("](");
+                out.push_str(&escape_markdown_v2_url(url));
+                out.push(')');
+            }
+            TextMention(ref user) => {
+                out.push_str(&format!("](tg://user?id={})", user.id));
+            }
+            CustomEmoji(ref id) => {
+                out.push_str("](tg://emoji?id=");
+                out.push_str(&escape_markdown_v2_url(id));
+                out.push(')');
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Span<'a> {
+    start: usize,
+    end: usize,
+    kind: &'a MessageEntityKind,
+}
+
+fn is_code(kind: &MessageEntityKind) -> bool {
+    matches!(*kind, MessageEntityKind::Code | MessageEntityKind::Pre(_))
+}
+
+fn render(text: &str, entities: &[MessageEntity], markup: &dyn Markup) -> String {
+    let mut spans: Vec<Span> = entities
+        .iter()
+        .filter_map(|entity| {
+            let range = entity.byte_range(text)?;
+            if range.start == range.end {
+                return None;
+            }
+            Some(Span {
+                start: range.start,
+                end: range.end,
+                kind: &entity.kind,
+            })
+        })
+        .collect();
+    // Outer entities go first, so nested entities are opened after them.
+    spans.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut boundaries: Vec<usize> = spans.iter().flat_map(|s| vec![s.start, s.end]).collect();
+    boundaries.sort();
+    boundaries.dedup();
+
+    let mut out = String::with_capacity(text.len());
+    let mut stack: Vec<&Span> = Vec::new();
+    let mut next = 0;
+    let mut pos = 0;
+
+    for boundary in boundaries {
+        let in_code = stack.iter().any(|span| is_code(span.kind));
+        out.push_str(&markup.escape(&text[pos..boundary], in_code));
+        pos = boundary;
+
+        // Close finished entities. Entities opened after them are closed as well
+        // and reopened afterwards, so the markup stays properly nested.
+        if let Some(index) = stack.iter().position(|span| span.end <= boundary) {
+            let closed = stack.split_off(index);
+            for span in closed.iter().rev() {
+                markup.close(span.kind, &mut out);
+            }
+            for span in closed {
+                if span.end > boundary {
+                    markup.open(span.kind, &mut out);
+                    stack.push(span);
+                }
+            }
+        }
+
+        while next < spans.len() && spans[next].start == boundary {
+            let span = &spans[next];
+            next += 1;
+            // Code blocks can't contain any other formatting.
+            if stack.iter().any(|span| is_code(span.kind)) {
+                continue;
+            }
+            markup.open(span.kind, &mut out);
+            stack.push(span);
+        }
+    }
+
+    out.push_str(&markup.escape(&text[pos..], false));
+    out
+}
@@ -0,0 +1,205 @@
+use crate::formatting::builder::FormattedText;
+use crate::types::*;
+
+/// Text of a part together with its entities.
+pub type TextPart = (String, Vec<MessageEntity>);
+
+/// Maximum length of a message text in UTF-16 code units.
+pub const MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Maximum length of a media caption in UTF-16 code units.
+pub const MAX_CAPTION_LENGTH: usize = 1024;
+
+/// Split `text` with `entities` into parts of at most `limit` UTF-16 code units.
+///
+/// Text is split on paragraph boundaries if possible, then on line boundaries, then on
+/// whitespace, and only as a last resort in the middle of a word. The whitespace at which
+/// the text was split is dropped. Boundaries inside links, mentions and inline code are
+/// avoided. Entities spanning several parts are cut, so every part keeps its formatting.
+///
+/// # Panics
+///
+/// Panics if `limit` is zero.
+pub fn split_text(text: &str, entities: &[MessageEntity], limit: usize) -> Vec<TextPart> {
+    split_with_limits(text, entities, limit, limit)
+}
+
+/// Split a media caption into the caption of at most `MAX_CAPTION_LENGTH` UTF-16 code units
+/// and the rest of the text in parts of at most `MAX_MESSAGE_LENGTH` UTF-16 code units,
+/// to be sent as separate messages. See [`split_text`](fn.split_text.html) for details.
+pub fn split_caption(text: &str, entities: &[MessageEntity]) -> (TextPart, Vec<TextPart>) {
+    let mut parts =
+        split_with_limits(text, entities, MAX_CAPTION_LENGTH, MAX_MESSAGE_LENGTH).into_iter();
+    let caption = parts.next().unwrap_or_default();
+    (caption, parts.collect())
+}
+
+fn split_with_limits(
+    text: &str,
+    entities: &[MessageEntity],
+    first_limit: usize,
+    limit: usize,
+) -> Vec<TextPart> {
+    assert!(first_limit > 0 && limit > 0, "limit must be positive");
+
+    // Byte and UTF-16 offsets of every char boundary, including the end of the text.
+    let mut offsets: Vec<(usize, usize)> = Vec::with_capacity(text.len() + 1);
+    let mut utf16_offset = 0;
+    for (byte_offset, c) in text.char_indices() {
+        offsets.push((byte_offset, utf16_offset));
+        utf16_offset += c.len_utf16();
+    }
+    offsets.push((text.len(), utf16_offset));
+
+    let mut parts = Vec::new();
+    let mut start = 0; // index into `offsets`
+    let last = offsets.len() - 1;
+
+    while start < last {
+        let limit = if parts.is_empty() { first_limit } else { limit };
+        let start_utf16 = offsets[start].1;
+        let mut end = start;
+        while end < last && offsets[end + 1].1 - start_utf16 <= limit {
+            end += 1;
+        }
+        if end == start {
+            // Single character longer than limit, take it anyway.
+            end = start + 1;
+        }
+
+        let (cut, next) = if end == last {
+            (end, end)
+        } else {
+            find_break(text, entities, &offsets, start, end)
+        };
+
+        parts.push(make_part(text, entities, &offsets, start, cut));
+        start = next;
+    }
+
+    parts
+}
+
+impl FormattedText {
+    /// Split into parts of at most `limit` UTF-16 code units,
+    /// see [`split_text`](fn.split_text.html) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    pub fn split(&self, limit: usize) -> Vec<FormattedText> {
+        split_text(self.as_str(), self.entities(), limit)
+            .into_iter()
+            .map(|(text, entities)| FormattedText::from_parts(text, entities))
+            .collect()
+    }
+
+    /// Split into a media caption and the rest of the text,
+    /// see [`split_caption`](fn.split_caption.html) for details.
+    pub fn split_caption(&self) -> (FormattedText, Vec<FormattedText>) {
+        let ((text, entities), rest) = split_caption(self.as_str(), self.entities());
+        let rest = rest
+            .into_iter()
+            .map(|(text, entities)| FormattedText::from_parts(text, entities))
+            .collect();
+        (FormattedText::from_parts(text, entities), rest)
+    }
+}
+
+/// Returns the index where the current part ends and the index where the next part starts.
+fn find_break(
+    text: &str,
+    entities: &[MessageEntity],
+    offsets: &[(usize, usize)],
+    start: usize,
+    end: usize,
+) -> (usize, usize) {
+    for avoid_entities in &[true, false] {
+        for separator in &[Separator::Paragraph, Separator::Line, Separator::Word] {
+            for index in (start + 1..=end).rev() {
+                let (byte_offset, utf16_offset) = offsets[index];
+                if let Some(len) = separator.len(&text[byte_offset..]) {
+                    if *avoid_entities && inside_unbreakable(entities, utf16_offset) {
+                        continue;
+                    }
+                    let next_byte = byte_offset + len;
+                    let next = index
+                        + offsets[index..]
+                            .iter()
+                            .position(|&(b, _)| b == next_byte)
+                            .unwrap_or(0);
+                    return (index, next);
+                }
+            }
+        }
+    }
+
+    (end, end)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Separator {
+    Paragraph,
+    Line,
+    Word,
+}
+
+impl Separator {
+    /// Length in bytes of the separator at the start of `rest`.
+    fn len(self, rest: &str) -> Option<usize> {
+        match self {
+            Separator::Paragraph if rest.starts_with("\n\n") => Some(2),
+            Separator::Line if rest.starts_with('\n') => Some(1),
+            Separator::Word => rest
+                .chars()
+                .next()
+                .filter(|c| c.is_whitespace())
+                .map(char::len_utf8),
+            _ => None,
+        }
+    }
+}
+
+fn inside_unbreakable(entities: &[MessageEntity], offset: usize) -> bool {
+    let offset = offset as Integer;
+    entities.iter().any(|entity| {
+        let unbreakable = matches!(
+            entity.kind,
+            MessageEntityKind::TextLink(_)
+                | MessageEntityKind::TextMention(_)
+                | MessageEntityKind::CustomEmoji(_)
+                | MessageEntityKind::Code
+        );
+        unbreakable && entity.offset < offset && offset < entity.offset + entity.length
+    })
+}
+
+fn make_part(
+    text: &str,
+    entities: &[MessageEntity],
+    offsets: &[(usize, usize)],
+    start: usize,
+    end: usize,
+) -> TextPart {
+    let (start_byte, start_utf16) = offsets[start];
+    let (end_byte, end_utf16) = offsets[end];
+    let (start_utf16, end_utf16) = (start_utf16 as Integer, end_utf16 as Integer);
+
+    let entities = entities
+        .iter()
+        .filter_map(|entity| {
+            let entity_start = entity.offset.max(start_utf16);
+            let entity_end = (entity.offset + entity.length).min(end_utf16);
+            if entity_start >= entity_end {
+                return None;
+            }
+            Some(MessageEntity::new(
+                entity.kind.clone(),
+                entity_start - start_utf16,
+                entity_end - entity_start,
+            ))
+        })
+        .collect();
+
+    (text[start_byte..end_byte].to_owned(), entities)
+}
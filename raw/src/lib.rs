@@ -1,10 +1,12 @@
 #[macro_use]
 extern crate serde_derive;
 
+pub mod formatting;
 pub mod requests;
 pub mod types;
 pub mod url;
 
+pub use crate::formatting::*;
 pub use crate::requests::*;
 pub use crate::types::*;
 pub use crate::url::*;
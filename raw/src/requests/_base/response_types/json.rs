@@ -23,6 +23,17 @@ impl<Type> JsonResponse for JsonIdResponse<Type> {
     }
 }
 
+pub struct JsonMessageIdResponse;
+
+impl JsonResponse for JsonMessageIdResponse {
+    type Raw = RawMessageId;
+    type Type = MessageId;
+
+    fn map(raw: Self::Raw) -> Self::Type {
+        raw.message_id
+    }
+}
+
 pub struct JsonTrueToUnitResponse;
 
 impl JsonResponse for JsonTrueToUnitResponse {
@@ -3,7 +3,7 @@ use std::ops::Not;
 use crate::requests::*;
 use crate::types::*;
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AnswerInlineQuery {
     inline_query_id: InlineQueryId,
     results: Vec<InlineQueryResult>,
@@ -61,6 +61,21 @@ impl AnswerInlineQuery {
         self.results.push(result.into());
     }
 
+    /// Results sent in the answer.
+    pub fn results(&self) -> &[InlineQueryResult] {
+        &self.results
+    }
+
+    /// Time in seconds Telegram may cache the answer for, `None` for the default of 300 seconds.
+    pub fn cached_for(&self) -> Option<Integer> {
+        self.cache_time
+    }
+
+    /// Whether Telegram may return the cached answer only to the user who sent the query.
+    pub fn personal(&self) -> bool {
+        self.is_personal
+    }
+
     pub fn cache_time(&mut self, cache_time: Integer) -> &mut Self {
         self.cache_time = Some(cache_time);
         self
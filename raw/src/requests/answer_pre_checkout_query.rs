@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Once the user has confirmed their payment and shipping details, the Bot API sends
+/// the final confirmation in the form of an Update with the field pre_checkout_query.
+/// Use this method to respond to such pre-checkout queries.
+/// Note: The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct AnswerPreCheckoutQuery<'e> {
+    pre_checkout_query_id: PreCheckoutQueryId,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_message: Option<Cow<'e, str>>,
+}
+
+impl<'e> Request for AnswerPreCheckoutQuery<'e> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("answerPreCheckoutQuery"), self)
+    }
+}
+
+impl<'e> AnswerPreCheckoutQuery<'e> {
+    /// Everything is alright (goods are available, etc.) and the bot is ready
+    /// to proceed with the order.
+    pub fn new<Q>(query: Q) -> Self
+    where
+        Q: ToPreCheckoutQueryId,
+    {
+        Self {
+            pre_checkout_query_id: query.to_pre_checkout_query_id(),
+            ok: true,
+            error_message: None,
+        }
+    }
+
+    /// The checkout cannot be completed, `error_message` explains the reason
+    /// in human readable form.
+    pub fn error<Q, T>(query: Q, error_message: T) -> Self
+    where
+        Q: ToPreCheckoutQueryId,
+        T: Into<Cow<'e, str>>,
+    {
+        Self {
+            pre_checkout_query_id: query.to_pre_checkout_query_id(),
+            ok: false,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+/// Respond to pre-checkout queries.
+pub trait CanAnswerPreCheckoutQuery {
+    fn confirm<'e>(&self) -> AnswerPreCheckoutQuery<'e>;
+    fn reject<'e, T>(&self, error_message: T) -> AnswerPreCheckoutQuery<'e>
+    where
+        T: Into<Cow<'e, str>>;
+}
+
+impl<Q> CanAnswerPreCheckoutQuery for Q
+where
+    Q: ToPreCheckoutQueryId,
+{
+    fn confirm<'e>(&self) -> AnswerPreCheckoutQuery<'e> {
+        AnswerPreCheckoutQuery::new(self)
+    }
+
+    fn reject<'e, T>(&self, error_message: T) -> AnswerPreCheckoutQuery<'e>
+    where
+        T: Into<Cow<'e, str>>,
+    {
+        AnswerPreCheckoutQuery::error(self, error_message)
+    }
+}
@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// If you sent an invoice requesting a shipping address and the parameter is_flexible was
+/// specified, the Bot API will send an Update with a shipping_query field to the bot.
+/// Use this method to reply to shipping queries.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct AnswerShippingQuery<'e> {
+    shipping_query_id: ShippingQueryId,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_options: Option<Vec<ShippingOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_message: Option<Cow<'e, str>>,
+}
+
+impl<'e> Request for AnswerShippingQuery<'e> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("answerShippingQuery"), self)
+    }
+}
+
+impl<'e> AnswerShippingQuery<'e> {
+    /// Delivery to the specified address is possible, with the given shipping options.
+    pub fn new<Q>(query: Q, shipping_options: Vec<ShippingOption>) -> Self
+    where
+        Q: ToShippingQueryId,
+    {
+        Self {
+            shipping_query_id: query.to_shipping_query_id(),
+            ok: true,
+            shipping_options: Some(shipping_options),
+            error_message: None,
+        }
+    }
+
+    /// Delivery to the specified address is not possible, `error_message` explains why
+    /// the order cannot be processed.
+    pub fn error<Q, T>(query: Q, error_message: T) -> Self
+    where
+        Q: ToShippingQueryId,
+        T: Into<Cow<'e, str>>,
+    {
+        Self {
+            shipping_query_id: query.to_shipping_query_id(),
+            ok: false,
+            shipping_options: None,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+/// Reply to shipping queries.
+pub trait CanAnswerShippingQuery {
+    fn answer<'e>(&self, shipping_options: Vec<ShippingOption>) -> AnswerShippingQuery<'e>;
+    fn reject<'e, T>(&self, error_message: T) -> AnswerShippingQuery<'e>
+    where
+        T: Into<Cow<'e, str>>;
+}
+
+impl<Q> CanAnswerShippingQuery for Q
+where
+    Q: ToShippingQueryId,
+{
+    fn answer<'e>(&self, shipping_options: Vec<ShippingOption>) -> AnswerShippingQuery<'e> {
+        AnswerShippingQuery::new(self, shipping_options)
+    }
+
+    fn reject<'e, T>(&self, error_message: T) -> AnswerShippingQuery<'e>
+    where
+        T: Into<Cow<'e, str>>,
+    {
+        AnswerShippingQuery::error(self, error_message)
+    }
+}
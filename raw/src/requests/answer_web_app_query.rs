@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to set the result of an interaction with a Web App and send
+/// a corresponding message on behalf of the user to the chat from which the query originated.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct AnswerWebAppQuery<'s> {
+    web_app_query_id: Cow<'s, str>,
+    result: InlineQueryResult,
+}
+
+impl<'s> Request for AnswerWebAppQuery<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<SentWebAppMessage>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("answerWebAppQuery"), self)
+    }
+}
+
+impl<'s> AnswerWebAppQuery<'s> {
+    /// Answer the query with `web_app_query_id` from the Web App init data.
+    pub fn new<Q, R>(web_app_query_id: Q, result: R) -> Self
+    where
+        Q: Into<Cow<'s, str>>,
+        R: Into<InlineQueryResult>,
+    {
+        AnswerWebAppQuery {
+            web_app_query_id: web_app_query_id.into(),
+            result: result.into(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to approve a chat join request.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the can_invite_users administrator right.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct ApproveChatJoinRequest {
+    chat_id: ChatRef,
+    user_id: UserId,
+}
+
+impl Request for ApproveChatJoinRequest {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("approveChatJoinRequest"), self)
+    }
+}
+
+impl ApproveChatJoinRequest {
+    pub fn new<C, U>(chat: C, user: U) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+    {
+        ApproveChatJoinRequest {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+        }
+    }
+}
+
+/// Approve a chat join request.
+pub trait CanApproveChatJoinRequestForChat {
+    fn approve_join_request<O>(&self, other: O) -> ApproveChatJoinRequest
+    where
+        O: ToUserId;
+}
+
+impl<C> CanApproveChatJoinRequestForChat for C
+where
+    C: ToChatRef,
+{
+    fn approve_join_request<O>(&self, other: O) -> ApproveChatJoinRequest
+    where
+        O: ToUserId,
+    {
+        ApproveChatJoinRequest::new(self, other)
+    }
+}
+
+/// Approve a chat join request.
+pub trait CanApproveChatJoinRequestForUser {
+    fn approve_join_request_in<O>(&self, other: O) -> ApproveChatJoinRequest
+    where
+        O: ToChatRef;
+}
+
+impl<U> CanApproveChatJoinRequestForUser for U
+where
+    U: ToUserId,
+{
+    fn approve_join_request_in<O>(&self, other: O) -> ApproveChatJoinRequest
+    where
+        O: ToChatRef,
+    {
+        ApproveChatJoinRequest::new(other, self)
+    }
+}
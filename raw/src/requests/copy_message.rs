@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+use std::ops::Not;
+
+use crate::formatting::FormattedText;
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to copy messages of any kind. Service messages and invoice messages
+/// can't be copied. The method is analogous to `ForwardMessage`, but the copied message
+/// doesn't have a link to the original message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CopyMessage<'c> {
+    chat_id: ChatRef,
+    from_chat_id: ChatRef,
+    message_id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<Cow<'c, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    allow_sending_without_reply: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'c> Request for CopyMessage<'c> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonMessageIdResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("copyMessage"), self)
+    }
+}
+
+impl<'c> CopyMessage<'c> {
+    pub fn new<M, F, T>(message: M, from: F, to: T) -> Self
+    where
+        M: ToMessageId,
+        F: ToChatRef,
+        T: ToChatRef,
+    {
+        CopyMessage {
+            chat_id: to.to_chat_ref(),
+            from_chat_id: from.to_chat_ref(),
+            message_id: message.to_message_id(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            disable_notification: false,
+            reply_to_message_id: None,
+            allow_sending_without_reply: false,
+            reply_markup: None,
+        }
+    }
+
+    /// New caption for media. If not specified, the original caption is kept.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<Cow<'c, str>>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Special entities that appear in the new caption, which can be specified
+    /// instead of `parse_mode`.
+    pub fn caption_entities(&mut self, caption_entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Set new caption together with its entities.
+    pub fn formatted_caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let (text, entities) = caption.into().into_parts();
+        self.caption = Some(Cow::Owned(text));
+        self.parse_mode = None;
+        self.caption_entities = Some(entities);
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    /// Send the message even if the specified replied-to message is not found.
+    pub fn allow_sending_without_reply(&mut self) -> &mut Self {
+        self.allow_sending_without_reply = true;
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Copy message.
+pub trait CanCopyMessage {
+    fn copy<'c, T>(&self, to: T) -> CopyMessage<'c>
+    where
+        T: ToChatRef;
+}
+
+impl<M> CanCopyMessage for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn copy<'c, T>(&self, to: T) -> CopyMessage<'c>
+    where
+        T: ToChatRef,
+    {
+        CopyMessage::new(self.to_message_id(), self.to_source_chat(), to)
+    }
+}
@@ -0,0 +1,89 @@
+use std::borrow::Cow;
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to create an additional invite link for a chat.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the appropriate admin rights. The link can be revoked using `RevokeChatInviteLink`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CreateChatInviteLink<'s> {
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<Cow<'s, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expire_date: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    member_limit: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    creates_join_request: bool,
+}
+
+impl<'s> Request for CreateChatInviteLink<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<ChatInviteLink>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("createChatInviteLink"), self)
+    }
+}
+
+impl<'s> CreateChatInviteLink<'s> {
+    pub fn new<C>(chat: C) -> Self
+    where
+        C: ToChatRef,
+    {
+        CreateChatInviteLink {
+            chat_id: chat.to_chat_ref(),
+            name: None,
+            expire_date: None,
+            member_limit: None,
+            creates_join_request: false,
+        }
+    }
+
+    /// Invite link name; 0-32 characters.
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: Into<Cow<'s, str>>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Point in time (Unix timestamp) when the link will expire.
+    pub fn expire_date(&mut self, expire_date: Integer) -> &mut Self {
+        self.expire_date = Some(expire_date);
+        self
+    }
+
+    /// Maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999.
+    pub fn member_limit(&mut self, member_limit: Integer) -> &mut Self {
+        self.member_limit = Some(member_limit);
+        self
+    }
+
+    /// Users joining the chat via the link need to be approved by chat administrators.
+    /// Member limit can't be specified together with this option.
+    pub fn creates_join_request(&mut self) -> &mut Self {
+        self.creates_join_request = true;
+        self
+    }
+}
+
+/// Create an additional invite link for a chat.
+pub trait CanCreateChatInviteLink {
+    fn create_invite_link<'s>(&self) -> CreateChatInviteLink<'s>;
+}
+
+impl<C> CanCreateChatInviteLink for C
+where
+    C: ToChatRef,
+{
+    fn create_invite_link<'s>(&self) -> CreateChatInviteLink<'s> {
+        CreateChatInviteLink::new(self)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to decline a chat join request.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the can_invite_users administrator right.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeclineChatJoinRequest {
+    chat_id: ChatRef,
+    user_id: UserId,
+}
+
+impl Request for DeclineChatJoinRequest {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("declineChatJoinRequest"), self)
+    }
+}
+
+impl DeclineChatJoinRequest {
+    pub fn new<C, U>(chat: C, user: U) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+    {
+        DeclineChatJoinRequest {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+        }
+    }
+}
+
+/// Decline a chat join request.
+pub trait CanDeclineChatJoinRequestForChat {
+    fn decline_join_request<O>(&self, other: O) -> DeclineChatJoinRequest
+    where
+        O: ToUserId;
+}
+
+impl<C> CanDeclineChatJoinRequestForChat for C
+where
+    C: ToChatRef,
+{
+    fn decline_join_request<O>(&self, other: O) -> DeclineChatJoinRequest
+    where
+        O: ToUserId,
+    {
+        DeclineChatJoinRequest::new(self, other)
+    }
+}
+
+/// Decline a chat join request.
+pub trait CanDeclineChatJoinRequestForUser {
+    fn decline_join_request_in<O>(&self, other: O) -> DeclineChatJoinRequest
+    where
+        O: ToChatRef;
+}
+
+impl<U> CanDeclineChatJoinRequestForUser for U
+where
+    U: ToUserId,
+{
+    fn decline_join_request_in<O>(&self, other: O) -> DeclineChatJoinRequest
+    where
+        O: ToChatRef,
+    {
+        DeclineChatJoinRequest::new(other, self)
+    }
+}
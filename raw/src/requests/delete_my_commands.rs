@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to delete the list of the bot's commands for the given scope and user language.
+/// After deletion, higher level commands will be shown to affected users.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteMyCommands<'s> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<BotCommandScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<Cow<'s, str>>,
+}
+
+impl<'s> Request for DeleteMyCommands<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteMyCommands"), self)
+    }
+}
+
+impl<'s> DeleteMyCommands<'s> {
+    pub fn new() -> Self {
+        DeleteMyCommands {
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    /// Scope of users for which the commands are relevant.
+    /// Defaults to `BotCommandScope::Default`.
+    pub fn scope(&mut self, scope: BotCommandScope) -> &mut Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied
+    /// to all users from the given scope, for whose language there are no dedicated commands.
+    pub fn language_code<T>(&mut self, language_code: T) -> &mut Self
+    where
+        T: Into<Cow<'s, str>>,
+    {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
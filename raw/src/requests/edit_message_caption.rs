@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::formatting::FormattedText;
 use crate::requests::*;
 use crate::types::*;
 
@@ -11,6 +12,10 @@ pub struct EditMessageCaption<'s> {
     message_id: MessageId,
     caption: Cow<'s, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
 
@@ -34,10 +39,36 @@ impl<'s> EditMessageCaption<'s> {
             chat_id: chat.to_chat_ref(),
             message_id: message_id.to_message_id(),
             caption: caption.into(),
+            parse_mode: None,
+            caption_entities: None,
             reply_markup: None,
         }
     }
 
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Special entities that appear in the caption, which can be specified
+    /// instead of `parse_mode`.
+    pub fn caption_entities(&mut self, caption_entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Set caption together with its entities.
+    pub fn formatted_caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let (text, entities) = caption.into().into_parts();
+        self.caption = Cow::Owned(text);
+        self.parse_mode = None;
+        self.caption_entities = Some(entities);
+        self
+    }
+
     pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
     where
         R: Into<ReplyMarkup>,
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::ops::Not;
 
+use crate::formatting::FormattedText;
 use crate::requests::*;
 use crate::types::*;
 
@@ -13,6 +14,8 @@ pub struct EditMessageText<'s> {
     text: Cow<'s, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Not::not")]
     disable_web_page_preview: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -40,6 +43,7 @@ impl<'s> EditMessageText<'s> {
             message_id: message_id.to_message_id(),
             text: text.into(),
             parse_mode: None,
+            entities: None,
             disable_web_page_preview: false,
             reply_markup: None,
         }
@@ -50,6 +54,25 @@ impl<'s> EditMessageText<'s> {
         self
     }
 
+    /// Special entities that appear in the message text, which can be specified
+    /// instead of `parse_mode`.
+    pub fn entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Set message text together with its entities.
+    pub fn formatted_text<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let (text, entities) = text.into().into_parts();
+        self.text = Cow::Owned(text);
+        self.parse_mode = None;
+        self.entities = Some(entities);
+        self
+    }
+
     pub fn disable_preview(&mut self) -> &mut Self {
         self.disable_web_page_preview = true;
         self
@@ -0,0 +1,52 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get the current value of the bot's menu button in a private chat,
+/// or the default menu button.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetChatMenuButton {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_id: Option<ChatRef>,
+}
+
+impl Request for GetChatMenuButton {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<MenuButton>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getChatMenuButton"), self)
+    }
+}
+
+impl GetChatMenuButton {
+    /// Get the default menu button.
+    pub fn new() -> Self {
+        GetChatMenuButton { chat_id: None }
+    }
+
+    /// Private chat to get the menu button of.
+    pub fn chat<C>(&mut self, chat: C) -> &mut Self
+    where
+        C: ToChatRef,
+    {
+        self.chat_id = Some(chat.to_chat_ref());
+        self
+    }
+}
+
+/// Get the bot's menu button in a private chat.
+pub trait CanGetChatMenuButton {
+    fn get_menu_button(&self) -> GetChatMenuButton;
+}
+
+impl<C> CanGetChatMenuButton for C
+where
+    C: ToChatRef,
+{
+    fn get_menu_button(&self) -> GetChatMenuButton {
+        let mut request = GetChatMenuButton::new();
+        request.chat(self);
+        request
+    }
+}
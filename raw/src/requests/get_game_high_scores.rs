@@ -0,0 +1,74 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get data for high score tables. Will return the score of the specified
+/// user and several of their neighbors in a game.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetGameHighScores {
+    user_id: UserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_id: Option<ChatRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inline_message_id: Option<String>,
+}
+
+impl Request for GetGameHighScores {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Vec<GameHighScore>>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getGameHighScores"), self)
+    }
+}
+
+impl GetGameHighScores {
+    pub fn new<C, M, U>(chat: C, message: M, user: U) -> Self
+    where
+        C: ToChatRef,
+        M: ToMessageId,
+        U: ToUserId,
+    {
+        GetGameHighScores {
+            user_id: user.to_user_id(),
+            chat_id: Some(chat.to_chat_ref()),
+            message_id: Some(message.to_message_id()),
+            inline_message_id: None,
+        }
+    }
+
+    /// High scores of a game message sent via the bot in inline mode.
+    pub fn inline<I, U>(inline_message_id: I, user: U) -> Self
+    where
+        I: Into<String>,
+        U: ToUserId,
+    {
+        GetGameHighScores {
+            user_id: user.to_user_id(),
+            chat_id: None,
+            message_id: None,
+            inline_message_id: Some(inline_message_id.into()),
+        }
+    }
+}
+
+/// Get data for high score tables of a game message.
+pub trait CanGetGameHighScores {
+    fn get_game_high_scores<U>(&self, user: U) -> GetGameHighScores
+    where
+        U: ToUserId;
+}
+
+impl<M> CanGetGameHighScores for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn get_game_high_scores<U>(&self, user: U) -> GetGameHighScores
+    where
+        U: ToUserId,
+    {
+        GetGameHighScores::new(self.to_source_chat(), self.to_message_id(), user)
+    }
+}
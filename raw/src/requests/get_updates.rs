@@ -74,4 +74,6 @@ pub enum AllowedUpdate {
     ShippingQuery,
     #[serde(rename = "pre_checkout_query")]
     PreCheckoutQuery,
+    #[serde(rename = "chat_join_request")]
+    ChatJoinRequest,
 }
@@ -1,3 +1,5 @@
+use std::ops::Not;
+
 use crate::requests::*;
 use crate::types::*;
 
@@ -10,6 +12,10 @@ use crate::types::*;
 pub struct KickChatMember {
     chat_id: ChatRef,
     user_id: UserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    until_date: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    revoke_messages: bool,
 }
 
 impl Request for KickChatMember {
@@ -30,8 +36,25 @@ impl KickChatMember {
         KickChatMember {
             chat_id: chat.to_chat_ref(),
             user_id: user.to_user_id(),
+            until_date: None,
+            revoke_messages: false,
         }
     }
+
+    /// Date when the user will be unbanned, unix time. If user is banned for more than
+    /// 366 days or less than 30 seconds from the current time they are considered
+    /// to be banned forever.
+    pub fn until_date(&mut self, until_date: Integer) -> &mut Self {
+        self.until_date = Some(until_date);
+        self
+    }
+
+    /// Delete all messages from the chat for the user that is being removed.
+    /// Always true for supergroups and channels.
+    pub fn revoke_messages(&mut self) -> &mut Self {
+        self.revoke_messages = true;
+        self
+    }
 }
 
 /// Kick a user from a group or a supergroup.
@@ -2,7 +2,19 @@
 pub mod _base;
 pub mod answer_callback_query;
 pub mod answer_inline_query;
+pub mod answer_pre_checkout_query;
+pub mod answer_shipping_query;
+pub mod answer_web_app_query;
+pub mod approve_chat_join_request;
+pub mod ban_chat_sender_chat;
+pub mod copy_message;
+pub mod create_chat_invite_link;
+pub mod decline_chat_join_request;
+pub mod delete_chat_photo;
+pub mod delete_chat_sticker_set;
 pub mod delete_message;
+pub mod delete_my_commands;
+pub mod edit_chat_invite_link;
 pub mod edit_message_caption;
 pub mod edit_message_live_location;
 pub mod edit_message_reply_markup;
@@ -13,32 +25,64 @@ pub mod get_chat;
 pub mod get_chat_administrators;
 pub mod get_chat_member;
 pub mod get_chat_members_count;
+pub mod get_chat_menu_button;
 pub mod get_file;
+pub mod get_game_high_scores;
 pub mod get_me;
+pub mod get_my_commands;
 pub mod get_updates;
 pub mod get_user_profile_photos;
 pub mod kick_chat_member;
 pub mod leave_chat;
 pub mod pin_chat_message;
+pub mod promote_chat_member;
+pub mod restrict_chat_member;
+pub mod revoke_chat_invite_link;
 pub mod send_audio;
 pub mod send_chat_action;
 pub mod send_contact;
+pub mod send_dice;
 pub mod send_document;
+pub mod send_game;
+pub mod send_invoice;
 pub mod send_location;
 pub mod send_message;
 pub mod send_photo;
 pub mod send_poll;
 pub mod send_venue;
 pub mod send_video;
+pub mod set_chat_administrator_custom_title;
+pub mod set_chat_description;
+pub mod set_chat_menu_button;
+pub mod set_chat_permissions;
+pub mod set_chat_photo;
+pub mod set_chat_sticker_set;
+pub mod set_chat_title;
+pub mod set_game_score;
+pub mod set_my_commands;
+pub mod set_passport_data_errors;
 pub mod stop_message_live_location;
 pub mod stop_poll;
 pub mod unban_chat_member;
+pub mod unpin_all_chat_messages;
 pub mod unpin_chat_message;
 
 pub use self::_base::*;
 pub use self::answer_callback_query::*;
 pub use self::answer_inline_query::*;
+pub use self::answer_pre_checkout_query::*;
+pub use self::answer_shipping_query::*;
+pub use self::answer_web_app_query::*;
+pub use self::approve_chat_join_request::*;
+pub use self::ban_chat_sender_chat::*;
+pub use self::copy_message::*;
+pub use self::create_chat_invite_link::*;
+pub use self::decline_chat_join_request::*;
+pub use self::delete_chat_photo::*;
+pub use self::delete_chat_sticker_set::*;
 pub use self::delete_message::*;
+pub use self::delete_my_commands::*;
+pub use self::edit_chat_invite_link::*;
 pub use self::edit_message_caption::*;
 pub use self::edit_message_live_location::*;
 pub use self::edit_message_reply_markup::*;
@@ -49,24 +93,44 @@ pub use self::get_chat::*;
 pub use self::get_chat_administrators::*;
 pub use self::get_chat_member::*;
 pub use self::get_chat_members_count::*;
+pub use self::get_chat_menu_button::*;
 pub use self::get_file::*;
+pub use self::get_game_high_scores::*;
 pub use self::get_me::*;
+pub use self::get_my_commands::*;
 pub use self::get_updates::*;
 pub use self::get_user_profile_photos::*;
 pub use self::kick_chat_member::*;
 pub use self::leave_chat::*;
 pub use self::pin_chat_message::*;
+pub use self::promote_chat_member::*;
+pub use self::restrict_chat_member::*;
+pub use self::revoke_chat_invite_link::*;
 pub use self::send_audio::*;
 pub use self::send_chat_action::*;
 pub use self::send_contact::*;
+pub use self::send_dice::*;
 pub use self::send_document::*;
+pub use self::send_game::*;
+pub use self::send_invoice::*;
 pub use self::send_location::*;
 pub use self::send_message::*;
 pub use self::send_photo::*;
 pub use self::send_poll::*;
 pub use self::send_venue::*;
 pub use self::send_video::*;
+pub use self::set_chat_administrator_custom_title::*;
+pub use self::set_chat_description::*;
+pub use self::set_chat_menu_button::*;
+pub use self::set_chat_permissions::*;
+pub use self::set_chat_photo::*;
+pub use self::set_chat_sticker_set::*;
+pub use self::set_chat_title::*;
+pub use self::set_game_score::*;
+pub use self::set_my_commands::*;
+pub use self::set_passport_data_errors::*;
 pub use self::stop_message_live_location::*;
 pub use self::stop_poll::*;
 pub use self::unban_chat_member::*;
+pub use self::unpin_all_chat_messages::*;
 pub use self::unpin_chat_message::*;
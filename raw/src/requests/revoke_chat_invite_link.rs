@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to revoke an invite link created by the bot.
+/// If the primary link is revoked, a new link is automatically generated.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct RevokeChatInviteLink<'s> {
+    chat_id: ChatRef,
+    invite_link: Cow<'s, str>,
+}
+
+impl<'s> Request for RevokeChatInviteLink<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<ChatInviteLink>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("revokeChatInviteLink"), self)
+    }
+}
+
+impl<'s> RevokeChatInviteLink<'s> {
+    pub fn new<C, L>(chat: C, invite_link: L) -> Self
+    where
+        C: ToChatRef,
+        L: Into<Cow<'s, str>>,
+    {
+        RevokeChatInviteLink {
+            chat_id: chat.to_chat_ref(),
+            invite_link: invite_link.into(),
+        }
+    }
+}
+
+/// Revoke an invite link created by the bot.
+pub trait CanRevokeChatInviteLink {
+    fn revoke_invite_link<'s, L>(&self, invite_link: L) -> RevokeChatInviteLink<'s>
+    where
+        L: Into<Cow<'s, str>>;
+}
+
+impl<C> CanRevokeChatInviteLink for C
+where
+    C: ToChatRef,
+{
+    fn revoke_invite_link<'s, L>(&self, invite_link: L) -> RevokeChatInviteLink<'s>
+    where
+        L: Into<Cow<'s, str>>,
+    {
+        RevokeChatInviteLink::new(self, invite_link)
+    }
+}
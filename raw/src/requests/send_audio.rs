@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::formatting::{FormattedText, MarkupError};
 use crate::requests::*;
 use crate::types::*;
 
@@ -11,6 +12,7 @@ pub struct SendAudio<'c, 'p, 't> {
     audio: InputFile,
     caption: Option<Cow<'c, str>>,
     parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
     duration: Option<Integer>,
     performer: Option<Cow<'p, str>>,
     title: Option<Cow<'t, str>>,
@@ -28,6 +30,7 @@ impl<'c, 'p, 't> ToMultipart for SendAudio<'c, 'p, 't> {
             (audio (raw));
             (caption (text), optional);
             (parse_mode (text), optional);
+            (caption_entities (json), optional);
             (duration (text), optional);
             (performer (text), optional);
             (title (text), optional);
@@ -48,6 +51,18 @@ impl<'c, 'p, 't> Request for SendAudio<'c, 'p, 't> {
     }
 }
 
+impl<'c, 'p, 't> SplitCaption<'c> for SendAudio<'c, 'p, 't> {
+    fn split_caption(mut self) -> Result<(Self, Vec<SendMessage<'c>>), MarkupError> {
+        let rest = split_media_caption(
+            &self.chat_id,
+            &mut self.caption,
+            &mut self.parse_mode,
+            &mut self.caption_entities,
+        )?;
+        Ok((self, rest))
+    }
+}
+
 impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
     pub fn new<C, V>(chat: C, audio: V) -> Self
     where
@@ -59,6 +74,7 @@ impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
             audio: audio.into(),
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             performer: None,
             title: None,
@@ -90,6 +106,25 @@ impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
         self
     }
 
+    /// Special entities that appear in the caption, which can be specified
+    /// instead of `parse_mode`.
+    pub fn caption_entities(&mut self, caption_entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Set caption together with its entities.
+    pub fn formatted_caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let (text, entities) = caption.into().into_parts();
+        self.caption = Some(Cow::Owned(text));
+        self.parse_mode = None;
+        self.caption_entities = Some(entities);
+        self
+    }
+
     pub fn duration(&mut self, duration: Integer) -> &mut Self {
         self.duration = Some(duration);
         self
@@ -0,0 +1,93 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send an animated emoji that will display a random value.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendDice {
+    chat_id: ChatRef,
+    emoji: DiceEmoji,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl Request for SendDice {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendDice"), self)
+    }
+}
+
+impl SendDice {
+    pub fn new<C>(chat: C, emoji: DiceEmoji) -> Self
+    where
+        C: ToChatRef,
+    {
+        SendDice {
+            chat_id: chat.to_chat_ref(),
+            emoji,
+            disable_notification: false,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Send a dice.
+pub trait CanSendDice {
+    fn dice(&self, emoji: DiceEmoji) -> SendDice;
+}
+
+impl<C> CanSendDice for C
+where
+    C: ToChatRef,
+{
+    fn dice(&self, emoji: DiceEmoji) -> SendDice {
+        SendDice::new(self, emoji)
+    }
+}
+
+/// Reply with a dice.
+pub trait CanReplySendDice {
+    fn dice_reply(&self, emoji: DiceEmoji) -> SendDice;
+}
+
+impl<M> CanReplySendDice for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn dice_reply(&self, emoji: DiceEmoji) -> SendDice {
+        let mut rq = self.to_source_chat().dice(emoji);
+        rq.reply_to(self.to_message_id());
+        rq
+    }
+}
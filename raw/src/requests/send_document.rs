@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::formatting::{FormattedText, MarkupError};
 use crate::requests::*;
 use crate::types::*;
 
@@ -13,6 +14,7 @@ pub struct SendDocument<'c> {
     thumb: Option<InputFile>,
     caption: Option<Cow<'c, str>>,
     parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
     reply_to_message_id: Option<MessageId>,
     disable_notification: bool,
     reply_markup: Option<ReplyMarkup>,
@@ -27,6 +29,7 @@ impl<'c> ToMultipart for SendDocument<'c> {
             (thumb (raw), optional);
             (caption (text), optional);
             (parse_mode (text), optional);
+            (caption_entities (json), optional);
             (reply_to_message_id (text), optional);
             (disable_notification (text), when_true);
             (reply_markup (json), optional);
@@ -43,6 +46,18 @@ impl<'c> Request for SendDocument<'c> {
     }
 }
 
+impl<'c> SplitCaption<'c> for SendDocument<'c> {
+    fn split_caption(mut self) -> Result<(Self, Vec<SendMessage<'c>>), MarkupError> {
+        let rest = split_media_caption(
+            &self.chat_id,
+            &mut self.caption,
+            &mut self.parse_mode,
+            &mut self.caption_entities,
+        )?;
+        Ok((self, rest))
+    }
+}
+
 impl<'c> SendDocument<'c> {
     pub fn new<C, V>(chat: C, document: V) -> Self
     where
@@ -55,6 +70,7 @@ impl<'c> SendDocument<'c> {
             thumb: None,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             reply_to_message_id: None,
             reply_markup: None,
             disable_notification: false,
@@ -82,6 +98,25 @@ impl<'c> SendDocument<'c> {
         self
     }
 
+    /// Special entities that appear in the caption, which can be specified
+    /// instead of `parse_mode`.
+    pub fn caption_entities(&mut self, caption_entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Set caption together with its entities.
+    pub fn formatted_caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let (text, entities) = caption.into().into_parts();
+        self.caption = Some(Cow::Owned(text));
+        self.parse_mode = None;
+        self.caption_entities = Some(entities);
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send a game.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendGame<'s> {
+    chat_id: ChatRef,
+    game_short_name: Cow<'s, str>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl<'s> Request for SendGame<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendGame"), self)
+    }
+}
+
+impl<'s> SendGame<'s> {
+    pub fn new<C, G>(chat: C, game_short_name: G) -> Self
+    where
+        C: ToChatRef,
+        G: Into<Cow<'s, str>>,
+    {
+        SendGame {
+            chat_id: chat.to_chat_ref(),
+            game_short_name: game_short_name.into(),
+            disable_notification: false,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    /// If empty, one 'Play game_title' button will be shown.
+    /// If not empty, the first button must launch the game.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Send a game.
+pub trait CanSendGame {
+    fn game<'s, G>(&self, game_short_name: G) -> SendGame<'s>
+    where
+        G: Into<Cow<'s, str>>;
+}
+
+impl<C> CanSendGame for C
+where
+    C: ToChatRef,
+{
+    fn game<'s, G>(&self, game_short_name: G) -> SendGame<'s>
+    where
+        G: Into<Cow<'s, str>>,
+    {
+        SendGame::new(self, game_short_name)
+    }
+}
+
+/// Reply with a game.
+pub trait CanReplySendGame {
+    fn game_reply<'s, G>(&self, game_short_name: G) -> SendGame<'s>
+    where
+        G: Into<Cow<'s, str>>;
+}
+
+impl<M> CanReplySendGame for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn game_reply<'s, G>(&self, game_short_name: G) -> SendGame<'s>
+    where
+        G: Into<Cow<'s, str>>,
+    {
+        let mut rq = self.to_source_chat().game(game_short_name);
+        rq.reply_to(self.to_message_id());
+        rq
+    }
+}
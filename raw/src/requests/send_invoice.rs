@@ -0,0 +1,284 @@
+use std::borrow::Cow;
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send invoices.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendInvoice<'s> {
+    chat_id: ChatRef,
+    title: Cow<'s, str>,
+    description: Cow<'s, str>,
+    payload: Cow<'s, str>,
+    provider_token: Cow<'s, str>,
+    currency: Cow<'s, str>,
+    prices: Vec<LabeledPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tip_amount: Option<Integer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggested_tip_amounts: Vec<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_parameter: Option<Cow<'s, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_data: Option<Cow<'s, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_url: Option<Cow<'s, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_size: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_height: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_name: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_phone_number: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_email: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_shipping_address: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    send_phone_number_to_provider: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    send_email_to_provider: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    is_flexible: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl<'s> Request for SendInvoice<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendInvoice"), self)
+    }
+}
+
+impl<'s> SendInvoice<'s> {
+    pub fn new<C, T, D, P, K, R>(
+        chat: C,
+        title: T,
+        description: D,
+        payload: P,
+        provider_token: K,
+        currency: R,
+        prices: Vec<LabeledPrice>,
+    ) -> Self
+    where
+        C: ToChatRef,
+        T: Into<Cow<'s, str>>,
+        D: Into<Cow<'s, str>>,
+        P: Into<Cow<'s, str>>,
+        K: Into<Cow<'s, str>>,
+        R: Into<Cow<'s, str>>,
+    {
+        SendInvoice {
+            chat_id: chat.to_chat_ref(),
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: provider_token.into(),
+            currency: currency.into(),
+            prices,
+            max_tip_amount: None,
+            suggested_tip_amounts: Vec::new(),
+            start_parameter: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+            disable_notification: false,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn add_price(&mut self, price: LabeledPrice) -> &mut Self {
+        self.prices.push(price);
+        self
+    }
+
+    /// The maximum accepted amount for tips in the smallest units of the currency.
+    pub fn max_tip_amount(&mut self, amount: Integer) -> &mut Self {
+        self.max_tip_amount = Some(amount);
+        self
+    }
+
+    /// Suggested amounts of tips in the smallest units of the currency.
+    /// At most 4 suggested tip amounts can be specified, they must be positive,
+    /// passed in a strictly increased order and must not exceed `max_tip_amount`.
+    pub fn suggested_tip_amounts(&mut self, amounts: Vec<Integer>) -> &mut Self {
+        self.suggested_tip_amounts = amounts;
+        self
+    }
+
+    /// Unique deep-linking parameter. If left empty, forwarded copies of the sent message
+    /// will have a Pay button, allowing multiple users to pay directly from the forwarded message.
+    pub fn start_parameter<T>(&mut self, start_parameter: T) -> &mut Self
+    where
+        T: Into<Cow<'s, str>>,
+    {
+        self.start_parameter = Some(start_parameter.into());
+        self
+    }
+
+    /// JSON-serialized data about the invoice, which will be shared with the payment provider.
+    pub fn provider_data<T>(&mut self, provider_data: T) -> &mut Self
+    where
+        T: Into<Cow<'s, str>>,
+    {
+        self.provider_data = Some(provider_data.into());
+        self
+    }
+
+    /// URL of the product photo for the invoice.
+    pub fn photo_url<T>(&mut self, photo_url: T) -> &mut Self
+    where
+        T: Into<Cow<'s, str>>,
+    {
+        self.photo_url = Some(photo_url.into());
+        self
+    }
+
+    pub fn photo_size(&mut self, size: Integer) -> &mut Self {
+        self.photo_size = Some(size);
+        self
+    }
+
+    pub fn photo_width(&mut self, width: Integer) -> &mut Self {
+        self.photo_width = Some(width);
+        self
+    }
+
+    pub fn photo_height(&mut self, height: Integer) -> &mut Self {
+        self.photo_height = Some(height);
+        self
+    }
+
+    pub fn need_name(&mut self) -> &mut Self {
+        self.need_name = true;
+        self
+    }
+
+    pub fn need_phone_number(&mut self) -> &mut Self {
+        self.need_phone_number = true;
+        self
+    }
+
+    pub fn need_email(&mut self) -> &mut Self {
+        self.need_email = true;
+        self
+    }
+
+    pub fn need_shipping_address(&mut self) -> &mut Self {
+        self.need_shipping_address = true;
+        self
+    }
+
+    pub fn send_phone_number_to_provider(&mut self) -> &mut Self {
+        self.send_phone_number_to_provider = true;
+        self
+    }
+
+    pub fn send_email_to_provider(&mut self) -> &mut Self {
+        self.send_email_to_provider = true;
+        self
+    }
+
+    /// The final price depends on the shipping method,
+    /// the bot will receive shipping queries.
+    pub fn flexible(&mut self) -> &mut Self {
+        self.is_flexible = true;
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    /// If empty, one 'Pay total price' button will be shown.
+    /// If not empty, the first button must be a Pay button.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Send an invoice.
+pub trait CanSendInvoice {
+    fn invoice<'s, T, D, P, K, R>(
+        &self,
+        title: T,
+        description: D,
+        payload: P,
+        provider_token: K,
+        currency: R,
+        prices: Vec<LabeledPrice>,
+    ) -> SendInvoice<'s>
+    where
+        T: Into<Cow<'s, str>>,
+        D: Into<Cow<'s, str>>,
+        P: Into<Cow<'s, str>>,
+        K: Into<Cow<'s, str>>,
+        R: Into<Cow<'s, str>>;
+}
+
+impl<C> CanSendInvoice for C
+where
+    C: ToChatRef,
+{
+    fn invoice<'s, T, D, P, K, R>(
+        &self,
+        title: T,
+        description: D,
+        payload: P,
+        provider_token: K,
+        currency: R,
+        prices: Vec<LabeledPrice>,
+    ) -> SendInvoice<'s>
+    where
+        T: Into<Cow<'s, str>>,
+        D: Into<Cow<'s, str>>,
+        P: Into<Cow<'s, str>>,
+        K: Into<Cow<'s, str>>,
+        R: Into<Cow<'s, str>>,
+    {
+        SendInvoice::new(
+            self,
+            title,
+            description,
+            payload,
+            provider_token,
+            currency,
+            prices,
+        )
+    }
+}
@@ -1,6 +1,10 @@
 use std::borrow::Cow;
 use std::ops::Not;
 
+use crate::formatting::{
+    parse_with_parse_mode, split_caption, split_text, FormattedText, MarkupError,
+    MAX_MESSAGE_LENGTH,
+};
 use crate::requests::*;
 use crate::types::*;
 
@@ -12,6 +16,8 @@ pub struct SendMessage<'s> {
     text: Cow<'s, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Not::not")]
     disable_web_page_preview: bool,
     #[serde(skip_serializing_if = "Not::not")]
@@ -41,6 +47,7 @@ impl<'s> SendMessage<'s> {
             chat_id: chat.to_chat_ref(),
             text: text.into(),
             parse_mode: None,
+            entities: None,
             disable_web_page_preview: false,
             disable_notification: false,
             reply_to_message_id: None,
@@ -53,6 +60,61 @@ impl<'s> SendMessage<'s> {
         self
     }
 
+    /// Special entities that appear in the message text, which can be specified
+    /// instead of `parse_mode`.
+    pub fn entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Set message text together with its entities.
+    pub fn formatted_text<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let (text, entities) = text.into().into_parts();
+        self.text = Cow::Owned(text);
+        self.parse_mode = None;
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Split the message into several messages of at most `MAX_MESSAGE_LENGTH` characters,
+    /// see [`split_text`](../formatting/split/fn.split_text.html) for details.
+    ///
+    /// Only the first message replies to the original message and only the last message
+    /// carries the reply markup. Text with `parse_mode` is parsed and the parts are sent
+    /// with entities instead, failing if the markup can't be parsed. The message is returned
+    /// as is if it fits into one message, so the result is never empty.
+    pub fn split(self) -> Result<Vec<SendMessage<'s>>, MarkupError> {
+        let (text, entities) = formatted_parts(&self.text, self.parse_mode, &self.entities)?;
+        let parts = split_text(&text, &entities, MAX_MESSAGE_LENGTH);
+        if parts.len() <= 1 {
+            return Ok(vec![self]);
+        }
+        let count = parts.len();
+
+        Ok(parts
+            .into_iter()
+            .enumerate()
+            .map(|(index, (text, entities))| {
+                let mut part = self.clone();
+                part.text = Cow::Owned(text);
+                if self.parse_mode.is_some() || self.entities.is_some() {
+                    part.parse_mode = None;
+                    part.entities = Some(entities);
+                }
+                if index != 0 {
+                    part.reply_to_message_id = None;
+                }
+                if index + 1 != count {
+                    part.reply_markup = None;
+                }
+                part
+            })
+            .collect())
+    }
+
     pub fn disable_preview(&mut self) -> &mut Self {
         self.disable_web_page_preview = true;
         self
@@ -80,6 +142,64 @@ impl<'s> SendMessage<'s> {
     }
 }
 
+/// Split a media caption longer than `MAX_CAPTION_LENGTH` characters.
+pub trait SplitCaption<'c>: Sized {
+    /// Split the caption into the caption of the media message and follow-up text messages
+    /// to the same chat, see [`split_caption`](../formatting/split/fn.split_caption.html)
+    /// for details. The request is returned as is if the caption fits, failing if its markup
+    /// can't be parsed.
+    fn split_caption(self) -> Result<(Self, Vec<SendMessage<'c>>), MarkupError>;
+}
+
+/// Cut the caption of a media request, returning the text messages with the rest of it.
+pub(crate) fn split_media_caption<'c>(
+    chat_id: &ChatRef,
+    caption: &mut Option<Cow<'c, str>>,
+    parse_mode: &mut Option<ParseMode>,
+    caption_entities: &mut Option<Vec<MessageEntity>>,
+) -> Result<Vec<SendMessage<'c>>, MarkupError> {
+    let text = match caption {
+        Some(text) => text,
+        None => return Ok(Vec::new()),
+    };
+    let (text, entities) = formatted_parts(text, *parse_mode, caption_entities)?;
+    let (first, rest) = split_caption(&text, &entities);
+    if rest.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let with_entities = parse_mode.is_some() || caption_entities.is_some();
+    let (first_text, first_entities) = first;
+    *caption = Some(Cow::Owned(first_text));
+    if with_entities {
+        *parse_mode = None;
+        *caption_entities = Some(first_entities);
+    }
+
+    Ok(rest
+        .into_iter()
+        .map(|(text, entities)| {
+            let mut message = SendMessage::new(chat_id.clone(), text);
+            if with_entities {
+                message.entities(entities);
+            }
+            message
+        })
+        .collect())
+}
+
+/// Text with entities, parsing it if `parse_mode` is set.
+fn formatted_parts(
+    text: &str,
+    parse_mode: Option<ParseMode>,
+    entities: &Option<Vec<MessageEntity>>,
+) -> Result<(String, Vec<MessageEntity>), MarkupError> {
+    match parse_mode {
+        Some(parse_mode) => parse_with_parse_mode(text, parse_mode).map(FormattedText::into_parts),
+        None => Ok((text.to_owned(), entities.clone().unwrap_or_default())),
+    }
+}
+
 /// Send text message.
 pub trait CanSendMessage {
     fn text<'s, T>(&self, text: T) -> SendMessage<'s>
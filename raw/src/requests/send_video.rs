@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::formatting::{FormattedText, MarkupError};
 use crate::requests::*;
 use crate::types::*;
 
@@ -11,6 +12,7 @@ pub struct SendVideo<'c> {
     video: InputFile,
     caption: Option<Cow<'c, str>>,
     parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
     duration: Option<Integer>,
     width: Option<Integer>,
     height: Option<Integer>,
@@ -29,6 +31,7 @@ impl<'c> ToMultipart for SendVideo<'c> {
             (video (raw));
             (caption (text), optional);
             (parse_mode (text), optional);
+            (caption_entities (json), optional);
             (duration (text), optional);
             (width (text), optional);
             (height (text), optional);
@@ -50,6 +53,18 @@ impl<'c> Request for SendVideo<'c> {
     }
 }
 
+impl<'c> SplitCaption<'c> for SendVideo<'c> {
+    fn split_caption(mut self) -> Result<(Self, Vec<SendMessage<'c>>), MarkupError> {
+        let rest = split_media_caption(
+            &self.chat_id,
+            &mut self.caption,
+            &mut self.parse_mode,
+            &mut self.caption_entities,
+        )?;
+        Ok((self, rest))
+    }
+}
+
 impl<'c> SendVideo<'c> {
     pub fn new<C, V>(chat: C, video: V) -> Self
     where
@@ -61,6 +76,7 @@ impl<'c> SendVideo<'c> {
             video: video.into(),
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             width: None,
             height: None,
@@ -93,6 +109,25 @@ impl<'c> SendVideo<'c> {
         self
     }
 
+    /// Special entities that appear in the caption, which can be specified
+    /// instead of `parse_mode`.
+    pub fn caption_entities(&mut self, caption_entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(caption_entities);
+        self
+    }
+
+    /// Set caption together with its entities.
+    pub fn formatted_caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<FormattedText>,
+    {
+        let (text, entities) = caption.into().into_parts();
+        self.caption = Some(Cow::Owned(text));
+        self.parse_mode = None;
+        self.caption_entities = Some(entities);
+        self
+    }
+
     pub fn duration(&mut self, duration: Integer) -> &mut Self {
         self.duration = Some(duration);
         self
@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to set a custom title for an administrator in a supergroup promoted by the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatAdministratorCustomTitle<'s> {
+    chat_id: ChatRef,
+    user_id: UserId,
+    custom_title: Cow<'s, str>,
+}
+
+impl<'s> Request for SetChatAdministratorCustomTitle<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatAdministratorCustomTitle"), self)
+    }
+}
+
+impl<'s> SetChatAdministratorCustomTitle<'s> {
+    pub fn new<C, U, T>(chat: C, user: U, custom_title: T) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+        T: Into<Cow<'s, str>>,
+    {
+        SetChatAdministratorCustomTitle {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+            custom_title: custom_title.into(),
+        }
+    }
+}
+
+/// Set a custom title for an administrator in a supergroup.
+pub trait CanSetChatAdministratorCustomTitleForChat {
+    fn set_custom_title<'s, O, T>(
+        &self,
+        other: O,
+        custom_title: T,
+    ) -> SetChatAdministratorCustomTitle<'s>
+    where
+        O: ToUserId,
+        T: Into<Cow<'s, str>>;
+}
+
+impl<C> CanSetChatAdministratorCustomTitleForChat for C
+where
+    C: ToChatRef,
+{
+    fn set_custom_title<'s, O, T>(
+        &self,
+        other: O,
+        custom_title: T,
+    ) -> SetChatAdministratorCustomTitle<'s>
+    where
+        O: ToUserId,
+        T: Into<Cow<'s, str>>,
+    {
+        SetChatAdministratorCustomTitle::new(self, other, custom_title)
+    }
+}
+
+/// Set a custom title for an administrator in a supergroup.
+pub trait CanSetChatAdministratorCustomTitleForUser {
+    fn set_custom_title_in<'s, O, T>(
+        &self,
+        other: O,
+        custom_title: T,
+    ) -> SetChatAdministratorCustomTitle<'s>
+    where
+        O: ToChatRef,
+        T: Into<Cow<'s, str>>;
+}
+
+impl<U> CanSetChatAdministratorCustomTitleForUser for U
+where
+    U: ToUserId,
+{
+    fn set_custom_title_in<'s, O, T>(
+        &self,
+        other: O,
+        custom_title: T,
+    ) -> SetChatAdministratorCustomTitle<'s>
+    where
+        O: ToChatRef,
+        T: Into<Cow<'s, str>>,
+    {
+        SetChatAdministratorCustomTitle::new(other, self, custom_title)
+    }
+}
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the description of a group, a supergroup or a channel.
+/// The bot must be an administrator in the chat for this to work and must have the
+/// appropriate admin rights. Pass an empty string to remove the description.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatDescription<'s> {
+    chat_id: ChatRef,
+    description: Cow<'s, str>,
+}
+
+impl<'s> Request for SetChatDescription<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatDescription"), self)
+    }
+}
+
+impl<'s> SetChatDescription<'s> {
+    pub fn new<C, T>(chat: C, description: T) -> Self
+    where
+        C: ToChatRef,
+        T: Into<Cow<'s, str>>,
+    {
+        SetChatDescription {
+            chat_id: chat.to_chat_ref(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Change the description of a chat.
+pub trait CanSetChatDescription {
+    fn set_description<'s, T>(&self, description: T) -> SetChatDescription<'s>
+    where
+        T: Into<Cow<'s, str>>;
+}
+
+impl<C> CanSetChatDescription for C
+where
+    C: ToChatRef,
+{
+    fn set_description<'s, T>(&self, description: T) -> SetChatDescription<'s>
+    where
+        T: Into<Cow<'s, str>>,
+    {
+        SetChatDescription::new(self, description)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the bot's menu button in a private chat, or the default menu button.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatMenuButton {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_id: Option<ChatRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    menu_button: Option<MenuButton>,
+}
+
+impl Request for SetChatMenuButton {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatMenuButton"), self)
+    }
+}
+
+impl SetChatMenuButton {
+    /// Change the default menu button to `MenuButton::Default`.
+    pub fn new() -> Self {
+        SetChatMenuButton {
+            chat_id: None,
+            menu_button: None,
+        }
+    }
+
+    /// Private chat where the menu button will be changed.
+    /// If not specified, the default menu button will be changed.
+    pub fn chat<C>(&mut self, chat: C) -> &mut Self
+    where
+        C: ToChatRef,
+    {
+        self.chat_id = Some(chat.to_chat_ref());
+        self
+    }
+
+    /// The new menu button. Defaults to `MenuButton::Default`.
+    pub fn menu_button(&mut self, menu_button: MenuButton) -> &mut Self {
+        self.menu_button = Some(menu_button);
+        self
+    }
+}
+
+/// Change the bot's menu button in a private chat.
+pub trait CanSetChatMenuButton {
+    fn set_menu_button(&self, menu_button: MenuButton) -> SetChatMenuButton;
+}
+
+impl<C> CanSetChatMenuButton for C
+where
+    C: ToChatRef,
+{
+    fn set_menu_button(&self, menu_button: MenuButton) -> SetChatMenuButton {
+        let mut request = SetChatMenuButton::new();
+        request.chat(self).menu_button(menu_button);
+        request
+    }
+}
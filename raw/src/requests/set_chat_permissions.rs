@@ -0,0 +1,47 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to set default chat permissions for all members.
+/// The bot must be an administrator in the group or a supergroup for this to work
+/// and must have the can_restrict_members admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatPermissions {
+    chat_id: ChatRef,
+    permissions: ChatPermissions,
+}
+
+impl Request for SetChatPermissions {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatPermissions"), self)
+    }
+}
+
+impl SetChatPermissions {
+    pub fn new<C>(chat: C, permissions: ChatPermissions) -> Self
+    where
+        C: ToChatRef,
+    {
+        SetChatPermissions {
+            chat_id: chat.to_chat_ref(),
+            permissions,
+        }
+    }
+}
+
+/// Set default chat permissions for all members.
+pub trait CanSetChatPermissions {
+    fn set_permissions(&self, permissions: ChatPermissions) -> SetChatPermissions;
+}
+
+impl<C> CanSetChatPermissions for C
+where
+    C: ToChatRef,
+{
+    fn set_permissions(&self, permissions: ChatPermissions) -> SetChatPermissions {
+        SetChatPermissions::new(self, permissions)
+    }
+}
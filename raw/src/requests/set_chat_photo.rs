@@ -0,0 +1,64 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to set a new profile photo for the chat. Photos can't be changed
+/// for private chats. The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatPhoto {
+    chat_id: ChatRef,
+    photo: InputFile,
+}
+
+impl ToMultipart for SetChatPhoto {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        multipart_map! {
+            self,
+            (chat_id (text));
+            (photo (raw));
+        }
+    }
+}
+
+impl Request for SetChatPhoto {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatPhoto"), self)
+    }
+}
+
+impl SetChatPhoto {
+    /// The photo can only be uploaded, file ids and urls are not accepted.
+    pub fn new<C, V>(chat: C, photo: V) -> Self
+    where
+        C: ToChatRef,
+        V: Into<InputFileUpload>,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+            photo: photo.into().into(),
+        }
+    }
+}
+
+/// Set a new profile photo for the chat.
+pub trait CanSetChatPhoto {
+    fn set_photo<V>(&self, photo: V) -> SetChatPhoto
+    where
+        V: Into<InputFileUpload>;
+}
+
+impl<C> CanSetChatPhoto for C
+where
+    C: ToChatRef,
+{
+    fn set_photo<V>(&self, photo: V) -> SetChatPhoto
+    where
+        V: Into<InputFileUpload>,
+    {
+        SetChatPhoto::new(self, photo)
+    }
+}
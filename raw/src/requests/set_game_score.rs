@@ -0,0 +1,131 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to set the score of the specified user in a game message sent by the bot.
+/// Returns an error, if the new score is not greater than the user's current score in
+/// the chat and force is not set.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetGameScore {
+    user_id: UserId,
+    score: Integer,
+    #[serde(skip_serializing_if = "Not::not")]
+    force: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_edit_message: bool,
+    chat_id: ChatRef,
+    message_id: MessageId,
+}
+
+impl Request for SetGameScore {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setGameScore"), self)
+    }
+}
+
+impl SetGameScore {
+    pub fn new<C, M, U>(chat: C, message: M, user: U, score: Integer) -> Self
+    where
+        C: ToChatRef,
+        M: ToMessageId,
+        U: ToUserId,
+    {
+        SetGameScore {
+            user_id: user.to_user_id(),
+            score,
+            force: false,
+            disable_edit_message: false,
+            chat_id: chat.to_chat_ref(),
+            message_id: message.to_message_id(),
+        }
+    }
+
+    /// Allow the high score to decrease.
+    /// This can be useful when fixing mistakes or banning cheaters.
+    pub fn force(&mut self) -> &mut Self {
+        self.force = true;
+        self
+    }
+
+    /// Don't automatically edit the game message to include the current scoreboard.
+    pub fn disable_edit_message(&mut self) -> &mut Self {
+        self.disable_edit_message = true;
+        self
+    }
+}
+
+/// Use this method to set the score of the specified user in a game message sent
+/// via the bot in inline mode.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetInlineGameScore {
+    user_id: UserId,
+    score: Integer,
+    #[serde(skip_serializing_if = "Not::not")]
+    force: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_edit_message: bool,
+    inline_message_id: String,
+}
+
+impl Request for SetInlineGameScore {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setGameScore"), self)
+    }
+}
+
+impl SetInlineGameScore {
+    pub fn new<I, U>(inline_message_id: I, user: U, score: Integer) -> Self
+    where
+        I: Into<String>,
+        U: ToUserId,
+    {
+        SetInlineGameScore {
+            user_id: user.to_user_id(),
+            score,
+            force: false,
+            disable_edit_message: false,
+            inline_message_id: inline_message_id.into(),
+        }
+    }
+
+    /// Allow the high score to decrease.
+    /// This can be useful when fixing mistakes or banning cheaters.
+    pub fn force(&mut self) -> &mut Self {
+        self.force = true;
+        self
+    }
+
+    /// Don't automatically edit the game message to include the current scoreboard.
+    pub fn disable_edit_message(&mut self) -> &mut Self {
+        self.disable_edit_message = true;
+        self
+    }
+}
+
+/// Set the score of the specified user in a game message.
+pub trait CanSetGameScore {
+    fn set_game_score<U>(&self, user: U, score: Integer) -> SetGameScore
+    where
+        U: ToUserId;
+}
+
+impl<M> CanSetGameScore for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn set_game_score<U>(&self, user: U, score: Integer) -> SetGameScore
+    where
+        U: ToUserId,
+    {
+        SetGameScore::new(self.to_source_chat(), self.to_message_id(), user, score)
+    }
+}
@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the list of the bot's commands.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetMyCommands<'s> {
+    commands: Vec<BotCommand>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<BotCommandScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<Cow<'s, str>>,
+}
+
+impl<'s> Request for SetMyCommands<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setMyCommands"), self)
+    }
+}
+
+impl<'s> SetMyCommands<'s> {
+    pub fn new(commands: Vec<BotCommand>) -> Self {
+        SetMyCommands {
+            commands,
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    /// Scope of users for which the commands are relevant.
+    /// Defaults to `BotCommandScope::Default`.
+    pub fn scope(&mut self, scope: BotCommandScope) -> &mut Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied
+    /// to all users from the given scope, for whose language there are no dedicated commands.
+    pub fn language_code<T>(&mut self, language_code: T) -> &mut Self
+    where
+        T: Into<Cow<'s, str>>,
+    {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
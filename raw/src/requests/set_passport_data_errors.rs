@@ -0,0 +1,46 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Informs a user that some of the Telegram Passport elements they provided contains errors.
+/// The user will not be able to re-submit their Passport to you until the errors are fixed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetPassportDataErrors {
+    user_id: UserId,
+    errors: Vec<PassportElementError>,
+}
+
+impl Request for SetPassportDataErrors {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setPassportDataErrors"), self)
+    }
+}
+
+impl SetPassportDataErrors {
+    pub fn new<U>(user: U, errors: Vec<PassportElementError>) -> Self
+    where
+        U: ToUserId,
+    {
+        SetPassportDataErrors {
+            user_id: user.to_user_id(),
+            errors,
+        }
+    }
+}
+
+/// Inform a user about errors in the Telegram Passport elements they provided.
+pub trait CanSetPassportDataErrors {
+    fn set_passport_data_errors(&self, errors: Vec<PassportElementError>) -> SetPassportDataErrors;
+}
+
+impl<U> CanSetPassportDataErrors for U
+where
+    U: ToUserId,
+{
+    fn set_passport_data_errors(&self, errors: Vec<PassportElementError>) -> SetPassportDataErrors {
+        SetPassportDataErrors::new(self, errors)
+    }
+}
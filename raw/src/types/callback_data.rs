@@ -0,0 +1,173 @@
+use std::error;
+use std::fmt;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use bincode::Options;
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::Sha256;
+
+use crate::types::*;
+
+/// Maximum length of callback data in bytes.
+pub const MAX_CALLBACK_DATA_LENGTH: usize = 64;
+
+/// Length in bytes of the truncated signature appended to encoded values.
+const SIGNATURE_LENGTH: usize = 8;
+
+/// Secret key signing callback data, so that data edited by a client is rejected.
+///
+/// Any secret known only to the bot works, e.g. its token. Changing the key invalidates
+/// the buttons of all previously sent messages.
+#[derive(Clone)]
+pub struct CallbackDataKey(Vec<u8>);
+
+impl CallbackDataKey {
+    pub fn new<S: AsRef<[u8]>>(secret: S) -> Self {
+        CallbackDataKey(secret.as_ref().to_vec())
+    }
+
+    fn signature(&self, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.0).expect("HMAC can take key of any size");
+        mac.update(payload);
+        mac
+    }
+}
+
+impl fmt::Debug for CallbackDataKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CallbackDataKey(..)")
+    }
+}
+
+fn options() -> impl Options {
+    bincode::DefaultOptions::new().reject_trailing_bytes()
+}
+
+/// Encode `value` in the compact binary form used by callback data.
+pub fn encode_compact<T: Serialize>(value: &T) -> Result<Vec<u8>, bincode::Error> {
+    options().serialize(value)
+}
+
+/// Decode a value encoded with `encode_compact`, rejecting trailing bytes.
+pub fn decode_compact<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, bincode::Error> {
+    options().deserialize(bytes)
+}
+
+/// Encode `value` as signed callback data, checking that it fits into
+/// `MAX_CALLBACK_DATA_LENGTH` bytes.
+///
+/// Values are encoded in a compact binary form followed by a truncated HMAC-SHA256 signature
+/// and converted to URL-safe base64, which leaves 40 bytes for the value itself. Integers
+/// below 251 and enum variants take a single byte, strings take a byte more than their length.
+pub fn encode_callback_data<T>(
+    key: &CallbackDataKey,
+    value: &T,
+) -> Result<String, CallbackDataError>
+where
+    T: Serialize,
+{
+    let mut data = encode_compact(value).map_err(CallbackDataError::Malformed)?;
+    let signature = key.signature(&data).finalize().into_bytes();
+    data.extend_from_slice(&signature[..SIGNATURE_LENGTH]);
+
+    let data = URL_SAFE_NO_PAD.encode(data);
+    if data.len() > MAX_CALLBACK_DATA_LENGTH {
+        return Err(CallbackDataError::TooLong(data.len()));
+    }
+    Ok(data)
+}
+
+/// Decode callback data produced by `encode_callback_data` with the same `key`.
+pub fn decode_callback_data<T>(key: &CallbackDataKey, data: &str) -> Result<T, CallbackDataError>
+where
+    T: DeserializeOwned,
+{
+    if data.len() > MAX_CALLBACK_DATA_LENGTH {
+        return Err(CallbackDataError::TooLong(data.len()));
+    }
+    let data = URL_SAFE_NO_PAD
+        .decode(data)
+        .map_err(|_| CallbackDataError::Tampered)?;
+    if data.len() < SIGNATURE_LENGTH {
+        return Err(CallbackDataError::Tampered);
+    }
+    let (payload, signature) = data.split_at(data.len() - SIGNATURE_LENGTH);
+    key.signature(payload)
+        .verify_truncated_left(signature)
+        .map_err(|_| CallbackDataError::Tampered)?;
+
+    decode_compact(payload).map_err(CallbackDataError::Malformed)
+}
+
+/// Error of encoding or decoding callback data.
+#[derive(Debug)]
+pub enum CallbackDataError {
+    /// Encoded data is longer than `MAX_CALLBACK_DATA_LENGTH` bytes.
+    TooLong(usize),
+    /// Callback query has no data, e.g. it originated from a game button.
+    Missing,
+    /// Data wasn't produced by `encode_callback_data` with the same key.
+    /// Be aware that a bad client can send arbitrary data.
+    Tampered,
+    /// Data can't be encoded or doesn't match the expected type.
+    Malformed(bincode::Error),
+}
+
+impl fmt::Display for CallbackDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallbackDataError::TooLong(length) => write!(
+                f,
+                "callback data is {} bytes long, at most {} bytes are allowed",
+                length, MAX_CALLBACK_DATA_LENGTH
+            ),
+            CallbackDataError::Missing => f.write_str("callback query has no data"),
+            CallbackDataError::Tampered => f.write_str("callback data has an invalid signature"),
+            CallbackDataError::Malformed(error) => write!(f, "malformed callback data: {}", error),
+        }
+    }
+}
+
+impl error::Error for CallbackDataError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CallbackDataError::Malformed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl CallbackQuery {
+    /// Decode `data` of the query into the type it was encoded from
+    /// with `InlineKeyboardButton::callback_data` and the same `key`.
+    pub fn decode_data<T>(&self, key: &CallbackDataKey) -> Result<T, CallbackDataError>
+    where
+        T: DeserializeOwned,
+    {
+        match self.data {
+            Some(ref data) => decode_callback_data(key, data),
+            None => Err(CallbackDataError::Missing),
+        }
+    }
+}
+
+impl InlineKeyboardButton {
+    /// Button with `data` encoded as callback data signed with `key`, see
+    /// `encode_callback_data`. Fails if the encoded data is longer than
+    /// `MAX_CALLBACK_DATA_LENGTH` bytes.
+    pub fn callback_data<T, D>(
+        text: T,
+        key: &CallbackDataKey,
+        data: &D,
+    ) -> Result<Self, CallbackDataError>
+    where
+        T: AsRef<str>,
+        D: Serialize,
+    {
+        Ok(Self::callback(text, encode_callback_data(key, data)?))
+    }
+}
@@ -18,4 +18,6 @@ pub struct CallbackQuery {
     /// Data associated with the callback button. Be aware that a bad client can
     /// send arbitrary data in this field.
     pub data: Option<String>,
+    /// Short name of a Game to be returned, serves as the unique identifier for the game.
+    pub game_short_name: Option<String>,
 }
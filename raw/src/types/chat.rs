@@ -3,19 +3,23 @@ use serde::de::{Deserialize, Deserializer, Error};
 use crate::types::*;
 
 /// This object represents a Telegram user or bot.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
 pub struct User {
     /// Unique identifier for this user or bot.
     pub id: UserId,
     /// User‘s or bot’s first name.
     pub first_name: String,
     /// User‘s or bot’s last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
     /// User‘s or bot’s username.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
     /// True, if this user is a bot.
+    #[serde(default)]
     pub is_bot: bool,
     /// IETF language tag of the user's language
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
 
@@ -0,0 +1,67 @@
+use crate::types::*;
+
+/// This object contains full information about a chat, as returned by `GetChat`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ChatFullInfo {
+    /// Basic information about the chat.
+    #[serde(flatten)]
+    pub chat: Chat,
+    /// Chat photo.
+    pub photo: Option<ChatPhoto>,
+    /// Bio of the other party in a private chat.
+    pub bio: Option<String>,
+    /// Description, for groups, supergroups and channel chats.
+    pub description: Option<String>,
+    /// The most recent pinned message (by sending date).
+    pub pinned_message: Option<Box<MessageOrChannelPost>>,
+    /// Default chat member permissions, for groups and supergroups.
+    pub permissions: Option<ChatPermissions>,
+    /// For supergroups, the minimum allowed delay between consecutive messages
+    /// sent by each unprivileged user.
+    pub slow_mode_delay: Option<Integer>,
+    /// The time after which all messages sent to the chat will be automatically deleted; in seconds.
+    pub message_auto_delete_time: Option<Integer>,
+    /// For supergroups, name of group sticker set.
+    pub sticker_set_name: Option<String>,
+    /// True, if the bot can change the group sticker set.
+    pub can_set_sticker_set: Option<bool>,
+    /// Unique identifier for the linked chat, i.e. the discussion group identifier
+    /// for a channel and vice versa; for supergroups and channel chats.
+    pub linked_chat_id: Option<ChatId>,
+    /// For supergroups, the location to which the supergroup is connected.
+    pub location: Option<ChatLocation>,
+}
+
+impl ChatFullInfo {
+    pub fn id(&self) -> ChatId {
+        self.chat.id()
+    }
+}
+
+/// This object represents a chat photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct ChatPhoto {
+    /// File identifier of small (160x160) chat photo.
+    /// This file_id can be used only for photo download and only for as long
+    /// as the photo is not changed.
+    pub small_file_id: String,
+    /// Unique file identifier of small (160x160) chat photo, which is supposed
+    /// to be the same over time and for different bots.
+    pub small_file_unique_id: String,
+    /// File identifier of big (640x640) chat photo.
+    /// This file_id can be used only for photo download and only for as long
+    /// as the photo is not changed.
+    pub big_file_id: String,
+    /// Unique file identifier of big (640x640) chat photo, which is supposed
+    /// to be the same over time and for different bots.
+    pub big_file_unique_id: String,
+}
+
+/// Represents a location to which a chat is connected.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ChatLocation {
+    /// The location to which the supergroup is connected. Can't be a live location.
+    pub location: Location,
+    /// Location address; 1-64 characters, as defined by the chat owner.
+    pub address: String,
+}
@@ -0,0 +1,16 @@
+use crate::types::*;
+
+/// Represents a join request sent to a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ChatJoinRequest {
+    /// Chat to which the request was sent
+    pub chat: Chat,
+    /// User that sent the join request
+    pub from: User,
+    /// Date the request was sent in Unix time
+    pub date: Integer,
+    /// Bio of the user.
+    pub bio: Option<String>,
+    /// Chat invite link that was used by the user to send the join request
+    pub invite_link: Option<ChatInviteLink>,
+}
@@ -11,6 +11,7 @@ pub enum ChatMemberStatus {
     Creator,
     Administrator,
     Member,
+    Restricted,
     Left,
     Kicked,
     #[doc(hidden)]
@@ -29,7 +30,7 @@ impl<'de> Deserialize<'de> for ChatMemberStatus {
             type Value = ChatMemberStatus;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("creator | administrator | member | left | kicked")
+                formatter.write_str("creator | administrator | member | restricted | left | kicked")
             }
 
             fn visit_str<E>(self, value: &str) -> Result<ChatMemberStatus, E>
@@ -40,6 +41,7 @@ impl<'de> Deserialize<'de> for ChatMemberStatus {
                     "creator" => Creator,
                     "administrator" => Administrator,
                     "member" => Member,
+                    "restricted" => Restricted,
                     "left" => Left,
                     "kicked" => Kicked,
                     _unknown => Unknown(value.to_string()),
@@ -58,10 +60,18 @@ pub struct ChatMember {
     pub user: User,
     /// The member's status in the chat.
     pub status: ChatMemberStatus,
+    ///Optional. Owner and administrators only. Custom title for this user
+    pub custom_title: Option<String>,
+    ///Optional. Owner and administrators only. True, if the user's presence in the chat is hidden
+    pub is_anonymous: Option<bool>,
     ///Optional. Restricted and kicked only. Date when restrictions will be lifted for this user, unix time
     pub until_date: Option<Integer>,
     ///Optional. Administrators only. True, if the bot is allowed to edit administrator privileges of that user
     pub can_be_edited: Option<bool>,
+    ///Optional. Administrators only. True, if the administrator can access the chat event log, chat statistics, message statistics in channels, see channel members, see anonymous administrators in supergroups and ignore slow mode
+    pub can_manage_chat: Option<bool>,
+    ///Optional. Administrators only. True, if the administrator can manage voice chats
+    pub can_manage_voice_chats: Option<bool>,
     ///Optional. Administrators only. True, if the administrator can change the chat title, photo and other settings
     pub can_change_info: Option<bool>,
     ///Optional. Administrators only. True, if the administrator can post in the channel, channels only
@@ -78,10 +88,14 @@ pub struct ChatMember {
     pub can_pin_messages: Option<bool>,
     ///Optional. Administrators only. True, if the administrator can add new administrators with a subset of his own privileges or demote administrators that he has promoted, directly or indirectly (promoted by administrators that were appointed by the user)
     pub can_promote_members: Option<bool>,
+    ///Optional. Restricted only. True, if the user is a member of the chat at the moment of the request
+    pub is_member: Option<bool>,
     ///Optional. Restricted only. True, if the user can send text messages, contacts, locations and venues
     pub can_send_messages: Option<bool>,
     ///Optional. Restricted only. True, if the user can send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages
     pub can_send_media_messages: Option<bool>,
+    ///Optional. Restricted only. True, if the user is allowed to send polls
+    pub can_send_polls: Option<bool>,
     ///Optional. Restricted only. True, if the user can send animations, games, stickers and use inline bots, implies can_send_media_messages
     pub can_send_other_messages: Option<bool>,
     ///Optional. Restricted only. True, if user may add web page previews to his messages, implies can_send_media_messages
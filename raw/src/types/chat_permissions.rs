@@ -0,0 +1,81 @@
+/// Describes actions that a non-administrator user is allowed to take in a chat.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct ChatPermissions {
+    /// True, if the user is allowed to send text messages, contacts, locations and venues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_messages: Option<bool>,
+    /// True, if the user is allowed to send audios, documents, photos, videos, video notes
+    /// and voice notes, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_media_messages: Option<bool>,
+    /// True, if the user is allowed to send polls, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_polls: Option<bool>,
+    /// True, if the user is allowed to send animations, games, stickers and use inline bots,
+    /// implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_other_messages: Option<bool>,
+    /// True, if the user is allowed to add web page previews to their messages,
+    /// implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_add_web_page_previews: Option<bool>,
+    /// True, if the user is allowed to change the chat title, photo and other settings.
+    /// Ignored in public supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+    /// True, if the user is allowed to invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+    /// True, if the user is allowed to pin messages. Ignored in public supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+}
+
+impl ChatPermissions {
+    /// Permissions with every field left unspecified.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn can_send_messages(&mut self, value: bool) -> &mut Self {
+        self.can_send_messages = Some(value);
+        self
+    }
+
+    pub fn can_send_media_messages(&mut self, value: bool) -> &mut Self {
+        self.can_send_media_messages = Some(value);
+        self
+    }
+
+    pub fn can_send_polls(&mut self, value: bool) -> &mut Self {
+        self.can_send_polls = Some(value);
+        self
+    }
+
+    pub fn can_send_other_messages(&mut self, value: bool) -> &mut Self {
+        self.can_send_other_messages = Some(value);
+        self
+    }
+
+    pub fn can_add_web_page_previews(&mut self, value: bool) -> &mut Self {
+        self.can_add_web_page_previews = Some(value);
+        self
+    }
+
+    pub fn can_change_info(&mut self, value: bool) -> &mut Self {
+        self.can_change_info = Some(value);
+        self
+    }
+
+    pub fn can_invite_users(&mut self, value: bool) -> &mut Self {
+        self.can_invite_users = Some(value);
+        self
+    }
+
+    pub fn can_pin_messages(&mut self, value: bool) -> &mut Self {
+        self.can_pin_messages = Some(value);
+        self
+    }
+}
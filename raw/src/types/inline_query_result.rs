@@ -3,7 +3,7 @@ use std::ops::Not;
 
 /// This object represents an incoming inline query.
 /// When the user sends an empty query, your bot could return some default or trending results.
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub enum InlineQueryResult {
     /// Represents a link to an mp3 audio file stored on the Telegram servers.
@@ -29,7 +29,7 @@ pub enum InlineQueryResult {
     /// By default, this animated MPEG-4 file will be sent by the user with an optional caption.
     /// Alternatively, you can use input_message_content to send a message with the specified
     /// content instead of the animation.
-    #[serde(rename = "mpeg4gif")]
+    #[serde(rename = "mpeg4_gif")]
     InlineQueryResultCachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
     /// Represents a link to a photo stored on the Telegram servers.
     /// By default, this photo will be sent by the user with an optional caption.
@@ -124,7 +124,7 @@ pub enum InlineQueryResult {
     InlineQueryResultVoice(InlineQueryResultVoice),
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultArticle {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -155,7 +155,7 @@ pub struct InlineQueryResultArticle {
     pub thumb_height: Option<Integer>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultPhoto {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -189,7 +189,7 @@ pub struct InlineQueryResultPhoto {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultGif {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -223,7 +223,7 @@ pub struct InlineQueryResultGif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultMpeg4Gif {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -256,7 +256,7 @@ pub struct InlineQueryResultMpeg4Gif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultVideo {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -294,7 +294,7 @@ pub struct InlineQueryResultVideo {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultAudio {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -321,7 +321,7 @@ pub struct InlineQueryResultAudio {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultVoice {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -334,9 +334,9 @@ pub struct InlineQueryResultVoice {
     /// Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
-    /// Audio duration in seconds
+    /// Recording duration in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub audio_duration: Option<Integer>,
+    pub voice_duration: Option<Integer>,
     /// Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
@@ -345,7 +345,7 @@ pub struct InlineQueryResultVoice {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultDocument {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -381,7 +381,7 @@ pub struct InlineQueryResultDocument {
     pub thumb_height: Option<Integer>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultLocation {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -411,7 +411,7 @@ pub struct InlineQueryResultLocation {
     pub thumb_height: Option<Integer>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultVenue {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -447,7 +447,7 @@ pub struct InlineQueryResultVenue {
     pub thumb_height: Option<Integer>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultContact {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -456,9 +456,11 @@ pub struct InlineQueryResultContact {
     /// Contact's first name
     pub first_name: String,
     /// Contact's last name
-    pub last_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
     /// Additional data about the contact in the form of a vCard, 0-2048 bytes
-    pub vcard: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
     /// Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
@@ -476,7 +478,7 @@ pub struct InlineQueryResultContact {
     pub thumb_height: Option<Integer>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultGame {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -487,7 +489,7 @@ pub struct InlineQueryResultGame {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedPhoto {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -513,7 +515,7 @@ pub struct InlineQueryResultCachedPhoto {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedGif {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -536,7 +538,7 @@ pub struct InlineQueryResultCachedGif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedMpeg4Gif {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -559,7 +561,7 @@ pub struct InlineQueryResultCachedMpeg4Gif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedSticker {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -573,7 +575,7 @@ pub struct InlineQueryResultCachedSticker {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedDocument {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -598,7 +600,7 @@ pub struct InlineQueryResultCachedDocument {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedVideo {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -623,7 +625,7 @@ pub struct InlineQueryResultCachedVideo {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedVoice {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -645,7 +647,7 @@ pub struct InlineQueryResultCachedVoice {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InlineQueryResultCachedAudio {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
@@ -665,6 +667,34 @@ pub struct InlineQueryResultCachedAudio {
     pub input_message_content: Option<InputMessageContent>,
 }
 
+impl InlineQueryResult {
+    /// Unique identifier of the result.
+    pub fn id(&self) -> &str {
+        match self {
+            InlineQueryResult::InlineQueryResultCachedAudio(result) => &result.id,
+            InlineQueryResult::InlineQueryResultCachedDocument(result) => &result.id,
+            InlineQueryResult::InlineQueryResultCachedGif(result) => &result.id,
+            InlineQueryResult::InlineQueryResultCachedMpeg4Gif(result) => &result.id,
+            InlineQueryResult::InlineQueryResultCachedPhoto(result) => &result.id,
+            InlineQueryResult::InlineQueryResultCachedSticker(result) => &result.id,
+            InlineQueryResult::InlineQueryResultCachedVideo(result) => &result.id,
+            InlineQueryResult::InlineQueryResultCachedVoice(result) => &result.id,
+            InlineQueryResult::InlineQueryResultArticle(result) => &result.id,
+            InlineQueryResult::InlineQueryResultAudio(result) => &result.id,
+            InlineQueryResult::InlineQueryResultContact(result) => &result.id,
+            InlineQueryResult::InlineQueryResultGame(result) => &result.id,
+            InlineQueryResult::InlineQueryResultDocument(result) => &result.id,
+            InlineQueryResult::InlineQueryResultGif(result) => &result.id,
+            InlineQueryResult::InlineQueryResultLocation(result) => &result.id,
+            InlineQueryResult::InlineQueryResultMpeg4Gif(result) => &result.id,
+            InlineQueryResult::InlineQueryResultPhoto(result) => &result.id,
+            InlineQueryResult::InlineQueryResultVenue(result) => &result.id,
+            InlineQueryResult::InlineQueryResultVideo(result) => &result.id,
+            InlineQueryResult::InlineQueryResultVoice(result) => &result.id,
+        }
+    }
+}
+
 impl InlineQueryResultArticle {
     pub fn new<T: Into<String>, U: Into<String>, V: Into<InputMessageContent>>(
         id: T,
@@ -690,6 +720,14 @@ impl InlineQueryResultArticle {
         self
     }
 
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = input_message_content.into();
+        self
+    }
+
     pub fn url<T: Into<String>>(&mut self, url: T) -> &mut Self {
         self.url = Some(url.into());
         self
@@ -721,7 +759,979 @@ impl InlineQueryResultArticle {
     }
 }
 
-// TODO: builders for InlineQueryResult types
+impl InlineQueryResultPhoto {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        photo_url: U,
+        thumb_url: V,
+    ) -> InlineQueryResultPhoto {
+        InlineQueryResultPhoto {
+            id: id.into(),
+            photo_url: photo_url.into(),
+            thumb_url: thumb_url.into(),
+            photo_width: None,
+            photo_height: None,
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn photo_width<T: Into<Integer>>(&mut self, photo_width: T) -> &mut Self {
+        self.photo_width = Some(photo_width.into());
+        self
+    }
+
+    pub fn photo_height<T: Into<Integer>>(&mut self, photo_height: T) -> &mut Self {
+        self.photo_height = Some(photo_height.into());
+        self
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultGif {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        gif_url: U,
+        thumb_url: V,
+    ) -> InlineQueryResultGif {
+        InlineQueryResultGif {
+            id: id.into(),
+            gif_url: gif_url.into(),
+            thumb_url: thumb_url.into(),
+            gif_width: None,
+            gif_height: None,
+            gif_duration: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn gif_width<T: Into<Integer>>(&mut self, gif_width: T) -> &mut Self {
+        self.gif_width = Some(gif_width.into());
+        self
+    }
+
+    pub fn gif_height<T: Into<Integer>>(&mut self, gif_height: T) -> &mut Self {
+        self.gif_height = Some(gif_height.into());
+        self
+    }
+
+    pub fn gif_duration<T: Into<Integer>>(&mut self, gif_duration: T) -> &mut Self {
+        self.gif_duration = Some(gif_duration.into());
+        self
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultMpeg4Gif {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        mpeg4_url: U,
+        thumb_url: V,
+    ) -> InlineQueryResultMpeg4Gif {
+        InlineQueryResultMpeg4Gif {
+            id: id.into(),
+            mpeg4_url: mpeg4_url.into(),
+            thumb_url: thumb_url.into(),
+            mpeg4_width: None,
+            mpeg4_height: None,
+            mpeg4_duration: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn mpeg4_width<T: Into<Integer>>(&mut self, mpeg4_width: T) -> &mut Self {
+        self.mpeg4_width = Some(mpeg4_width.into());
+        self
+    }
+
+    pub fn mpeg4_height<T: Into<Integer>>(&mut self, mpeg4_height: T) -> &mut Self {
+        self.mpeg4_height = Some(mpeg4_height.into());
+        self
+    }
+
+    pub fn mpeg4_duration<T: Into<Integer>>(&mut self, mpeg4_duration: T) -> &mut Self {
+        self.mpeg4_duration = Some(mpeg4_duration.into());
+        self
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultVideo {
+    pub fn new<
+        T: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+        W: Into<String>,
+        X: Into<String>,
+    >(
+        id: T,
+        video_url: U,
+        mime_type: V,
+        thumb_url: W,
+        title: X,
+    ) -> InlineQueryResultVideo {
+        InlineQueryResultVideo {
+            id: id.into(),
+            video_url: video_url.into(),
+            mime_type: mime_type.into(),
+            thumb_url: thumb_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            video_width: None,
+            video_height: None,
+            video_duration: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn video_width<T: Into<Integer>>(&mut self, video_width: T) -> &mut Self {
+        self.video_width = Some(video_width.into());
+        self
+    }
+
+    pub fn video_height<T: Into<Integer>>(&mut self, video_height: T) -> &mut Self {
+        self.video_height = Some(video_height.into());
+        self
+    }
+
+    pub fn video_duration<T: Into<Integer>>(&mut self, video_duration: T) -> &mut Self {
+        self.video_duration = Some(video_duration.into());
+        self
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultAudio {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        audio_url: U,
+        title: V,
+    ) -> InlineQueryResultAudio {
+        InlineQueryResultAudio {
+            id: id.into(),
+            audio_url: audio_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            performer: None,
+            audio_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn performer<T: Into<String>>(&mut self, performer: T) -> &mut Self {
+        self.performer = Some(performer.into());
+        self
+    }
+
+    pub fn audio_duration<T: Into<Integer>>(&mut self, audio_duration: T) -> &mut Self {
+        self.audio_duration = Some(audio_duration.into());
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultVoice {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        voice_url: U,
+        title: V,
+    ) -> InlineQueryResultVoice {
+        InlineQueryResultVoice {
+            id: id.into(),
+            voice_url: voice_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            voice_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn voice_duration<T: Into<Integer>>(&mut self, voice_duration: T) -> &mut Self {
+        self.voice_duration = Some(voice_duration.into());
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultDocument {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>, W: Into<String>>(
+        id: T,
+        title: U,
+        document_url: V,
+        mime_type: W,
+    ) -> InlineQueryResultDocument {
+        InlineQueryResultDocument {
+            id: id.into(),
+            title: title.into(),
+            document_url: document_url.into(),
+            mime_type: mime_type.into(),
+            caption: None,
+            parse_mode: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    pub fn thumb_url<T: Into<String>>(&mut self, thumb_url: T) -> &mut Self {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    pub fn thumb_width<T: Into<Integer>>(&mut self, thumb_width: T) -> &mut Self {
+        self.thumb_width = Some(thumb_width.into());
+        self
+    }
+
+    pub fn thumb_height<T: Into<Integer>>(&mut self, thumb_height: T) -> &mut Self {
+        self.thumb_height = Some(thumb_height.into());
+        self
+    }
+}
+
+impl InlineQueryResultLocation {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        latitude: Float,
+        longitude: Float,
+        title: U,
+    ) -> InlineQueryResultLocation {
+        InlineQueryResultLocation {
+            id: id.into(),
+            latitude,
+            longitude,
+            title: title.into(),
+            live_period: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    pub fn live_period<T: Into<Integer>>(&mut self, live_period: T) -> &mut Self {
+        self.live_period = Some(live_period.into());
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    pub fn thumb_url<T: Into<String>>(&mut self, thumb_url: T) -> &mut Self {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    pub fn thumb_width<T: Into<Integer>>(&mut self, thumb_width: T) -> &mut Self {
+        self.thumb_width = Some(thumb_width.into());
+        self
+    }
+
+    pub fn thumb_height<T: Into<Integer>>(&mut self, thumb_height: T) -> &mut Self {
+        self.thumb_height = Some(thumb_height.into());
+        self
+    }
+}
+
+impl InlineQueryResultVenue {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        latitude: Float,
+        longitude: Float,
+        title: U,
+        address: V,
+    ) -> InlineQueryResultVenue {
+        InlineQueryResultVenue {
+            id: id.into(),
+            latitude,
+            longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    pub fn foursquare_id<T: Into<String>>(&mut self, foursquare_id: T) -> &mut Self {
+        self.foursquare_id = Some(foursquare_id.into());
+        self
+    }
+
+    pub fn foursquare_type<T: Into<String>>(&mut self, foursquare_type: T) -> &mut Self {
+        self.foursquare_type = Some(foursquare_type.into());
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    pub fn thumb_url<T: Into<String>>(&mut self, thumb_url: T) -> &mut Self {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    pub fn thumb_width<T: Into<Integer>>(&mut self, thumb_width: T) -> &mut Self {
+        self.thumb_width = Some(thumb_width.into());
+        self
+    }
+
+    pub fn thumb_height<T: Into<Integer>>(&mut self, thumb_height: T) -> &mut Self {
+        self.thumb_height = Some(thumb_height.into());
+        self
+    }
+}
+
+impl InlineQueryResultContact {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        phone_number: U,
+        first_name: V,
+    ) -> InlineQueryResultContact {
+        InlineQueryResultContact {
+            id: id.into(),
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    pub fn last_name<T: Into<String>>(&mut self, last_name: T) -> &mut Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    pub fn vcard<T: Into<String>>(&mut self, vcard: T) -> &mut Self {
+        self.vcard = Some(vcard.into());
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    pub fn thumb_url<T: Into<String>>(&mut self, thumb_url: T) -> &mut Self {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    pub fn thumb_width<T: Into<Integer>>(&mut self, thumb_width: T) -> &mut Self {
+        self.thumb_width = Some(thumb_width.into());
+        self
+    }
+
+    pub fn thumb_height<T: Into<Integer>>(&mut self, thumb_height: T) -> &mut Self {
+        self.thumb_height = Some(thumb_height.into());
+        self
+    }
+}
+
+impl InlineQueryResultGame {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        game_short_name: U,
+    ) -> InlineQueryResultGame {
+        InlineQueryResultGame {
+            id: id.into(),
+            game_short_name: game_short_name.into(),
+            reply_markup: None,
+        }
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedPhoto {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        photo_file_id: U,
+    ) -> InlineQueryResultCachedPhoto {
+        InlineQueryResultCachedPhoto {
+            id: id.into(),
+            photo_file_id: photo_file_id.into(),
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedGif {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        gif_file_id: U,
+    ) -> InlineQueryResultCachedGif {
+        InlineQueryResultCachedGif {
+            id: id.into(),
+            gif_file_id: gif_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedMpeg4Gif {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        mpeg4_file_id: U,
+    ) -> InlineQueryResultCachedMpeg4Gif {
+        InlineQueryResultCachedMpeg4Gif {
+            id: id.into(),
+            mpeg4_file_id: mpeg4_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedSticker {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        sticker_file_id: U,
+    ) -> InlineQueryResultCachedSticker {
+        InlineQueryResultCachedSticker {
+            id: id.into(),
+            sticker_file_id: sticker_file_id.into(),
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedDocument {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        title: U,
+        document_file_id: V,
+    ) -> InlineQueryResultCachedDocument {
+        InlineQueryResultCachedDocument {
+            id: id.into(),
+            title: title.into(),
+            document_file_id: document_file_id.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedVideo {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        video_file_id: U,
+        title: V,
+    ) -> InlineQueryResultCachedVideo {
+        InlineQueryResultCachedVideo {
+            id: id.into(),
+            video_file_id: video_file_id.into(),
+            title: title.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedVoice {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        voice_file_id: U,
+        title: V,
+    ) -> InlineQueryResultCachedVoice {
+        InlineQueryResultCachedVoice {
+            id: id.into(),
+            voice_file_id: voice_file_id.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedAudio {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        audio_file_id: U,
+    ) -> InlineQueryResultCachedAudio {
+        InlineQueryResultCachedAudio {
+            id: id.into(),
+            audio_file_id: audio_file_id.into(),
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
 
 impl From<InlineQueryResultCachedAudio> for InlineQueryResult {
     fn from(audio: InlineQueryResultCachedAudio) -> Self {
@@ -826,7 +1836,7 @@ impl From<InlineQueryResultVoice> for InlineQueryResult {
 
 /// This object represents the content of a message to be sent as a result of an inline query.
 /// Telegram clients currently support the following 4 types:
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputMessageContent {
     /// Represents the content of a text message to be sent as the result of an inline query.
@@ -839,7 +1849,7 @@ pub enum InputMessageContent {
     InputContactMessageContent(InputContactMessageContent),
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InputTextMessageContent {
     /// Text of the message to be sent, 1-4096 characters
     pub message_text: String,
@@ -851,7 +1861,7 @@ pub struct InputTextMessageContent {
     pub disable_web_page_preview: bool,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InputLocationMessageContent {
     /// Latitude of the location in degrees
     pub latitude: Float,
@@ -862,7 +1872,7 @@ pub struct InputLocationMessageContent {
     pub live_period: Option<Integer>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InputVenueMessageContent {
     /// Latitude of the venue in degrees
     pub latitude: Float,
@@ -881,7 +1891,7 @@ pub struct InputVenueMessageContent {
     pub foursquare_type: Option<String>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InputContactMessageContent {
     /// Contact's phone number
     pub phone_number: String,
@@ -0,0 +1,64 @@
+use std::ops::Not;
+
+/// This object represents a parameter of the inline keyboard button used to automatically
+/// authorize a user. Serves as a great replacement for the Telegram Login Widget when
+/// the user is coming from Telegram.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct LoginUrl {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    forward_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bot_username: Option<String>,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    request_write_access: bool,
+}
+
+impl LoginUrl {
+    /// An HTTP URL to be opened with user authorization data added to the query string
+    /// when the button is pressed.
+    pub fn new<U: Into<String>>(url: U) -> Self {
+        LoginUrl {
+            url: url.into(),
+            forward_text: None,
+            bot_username: None,
+            request_write_access: false,
+        }
+    }
+
+    /// URL to be opened when the button is pressed.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// New text of the button in forwarded messages.
+    pub fn forward_text<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        self.forward_text = Some(text.into());
+        self
+    }
+
+    /// Username of a bot, which will be used for user authorization.
+    /// If not specified, the current bot's username will be assumed.
+    pub fn bot_username<T: Into<String>>(&mut self, username: T) -> &mut Self {
+        self.bot_username = Some(username.into());
+        self
+    }
+
+    /// Request the permission for your bot to send messages to the user.
+    pub fn request_write_access(&mut self) -> &mut Self {
+        self.request_write_access = true;
+        self
+    }
+}
+
+impl<'a> From<&'a str> for LoginUrl {
+    fn from(value: &'a str) -> Self {
+        LoginUrl::new(value)
+    }
+}
+
+impl From<String> for LoginUrl {
+    fn from(value: String) -> Self {
+        LoginUrl::new(value)
+    }
+}
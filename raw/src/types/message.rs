@@ -1,4 +1,7 @@
+use std::ops::Range;
+
 use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
 
 use crate::types::*;
 use crate::url::*;
@@ -19,6 +22,10 @@ pub struct Message {
     pub from: User,
     /// Date the message was sent in Unix time.
     pub date: Integer,
+    /// Sender of the message, sent on behalf of a chat. The channel itself for channel
+    /// messages, the supergroup itself for messages from anonymous group administrators,
+    /// the linked channel for messages automatically forwarded to the discussion group.
+    pub sender_chat: Option<Chat>,
     /// Conversation the message belongs to.
     pub chat: MessageChat,
     /// Information about the original message.
@@ -26,8 +33,19 @@ pub struct Message {
     /// For replies, the original message. Note that the Message object in this field will not
     /// contain further reply_to_message fields even if it itself is a reply.
     pub reply_to_message: Option<Box<MessageOrChannelPost>>,
+    /// Bot through which the message was sent.
+    pub via_bot: Option<User>,
     /// Date the message was last edited in Unix time.
     pub edit_date: Option<Integer>,
+    /// True, if the message can't be forwarded.
+    pub has_protected_content: bool,
+    /// Signature of the post author for messages in channels, or the custom title
+    /// of an anonymous group administrator.
+    pub author_signature: Option<String>,
+    /// The domain name of the website on which the user has logged in.
+    pub connected_website: Option<String>,
+    /// Inline keyboard attached to the message.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
     /// Kind of the message.
     pub kind: MessageKind,
 }
@@ -39,6 +57,8 @@ pub struct ChannelPost {
     pub id: MessageId,
     /// Date the message was sent in Unix time.
     pub date: Integer,
+    /// Sender of the message, sent on behalf of a chat, usually the channel itself.
+    pub sender_chat: Option<Chat>,
     /// Conversation the message belongs to.
     pub chat: Channel,
     /// Information about the original message.
@@ -46,8 +66,19 @@ pub struct ChannelPost {
     /// For replies, the original message. Note that the Message object in this field will not
     /// contain further reply_to_message fields even if it itself is a reply.
     pub reply_to_message: Option<Box<MessageOrChannelPost>>,
+    /// Bot through which the message was sent.
+    pub via_bot: Option<User>,
     /// Date the message was last edited in Unix time.
     pub edit_date: Option<Integer>,
+    /// True, if the message can't be forwarded.
+    pub has_protected_content: bool,
+    /// Signature of the post author for messages in channels, or the custom title
+    /// of an anonymous group administrator.
+    pub author_signature: Option<String>,
+    /// The domain name of the website on which the user has logged in.
+    pub connected_website: Option<String>,
+    /// Inline keyboard attached to the message.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
     /// Kind of the message.
     pub kind: MessageKind,
 }
@@ -59,6 +90,8 @@ pub struct Forward {
     pub date: Integer,
     /// Sender of the original message.
     pub from: ForwardFrom,
+    /// For messages forwarded from channels, signature of the post author if present.
+    pub signature: Option<String>,
 }
 
 /// Information about the source of the original message.
@@ -109,6 +142,8 @@ pub enum MessageKind {
         data: Document,
         /// Caption for the document, 0-200 characters.
         caption: Option<String>,
+        /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption.
+        caption_entities: Vec<MessageEntity>,
     },
     /// Message is a photo.
     Photo {
@@ -116,6 +151,8 @@ pub enum MessageKind {
         data: Vec<PhotoSize>,
         /// Caption for the photo, 0-200 characters.
         caption: Option<String>,
+        /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption.
+        caption_entities: Vec<MessageEntity>,
         /// The unique identifier of a media message group this message belongs to.
         media_group_id: Option<String>,
     },
@@ -130,6 +167,8 @@ pub enum MessageKind {
         data: Video,
         /// Caption for the video, 0-200 characters.
         caption: Option<String>,
+        /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption.
+        caption_entities: Vec<MessageEntity>,
         /// The unique identifier of a media message group this message belongs to.
         media_group_id: Option<String>,
     },
@@ -214,6 +253,64 @@ pub enum MessageKind {
         // contain further reply_to_message fields even if it is itself a reply.
         data: Box<MessageOrChannelPost>,
     },
+    /// Message is an invoice for a payment.
+    Invoice {
+        /// Information about the invoice.
+        data: Invoice,
+    },
+    /// Service message about a successful payment.
+    SuccessfulPayment {
+        /// Information about the payment.
+        data: SuccessfulPayment,
+    },
+    /// Message is a game.
+    Game {
+        /// Information about the game.
+        data: Game,
+    },
+    /// Message is a dice with random value.
+    Dice {
+        /// Information about the dice.
+        data: Dice,
+    },
+    /// Service message: a user in the chat triggered another user's proximity alert
+    /// while sharing Live Location.
+    ProximityAlertTriggered {
+        /// Information about the alert.
+        data: ProximityAlertTriggered,
+    },
+    /// Service message: auto-delete timer settings changed in the chat.
+    MessageAutoDeleteTimerChanged {
+        /// Information about the new settings.
+        data: MessageAutoDeleteTimerChanged,
+    },
+    /// Service message: video chat scheduled.
+    VideoChatScheduled {
+        /// Information about the scheduled video chat.
+        data: VideoChatScheduled,
+    },
+    /// Service message: video chat started.
+    VideoChatStarted,
+    /// Service message: video chat ended.
+    VideoChatEnded {
+        /// Information about the ended video chat.
+        data: VideoChatEnded,
+    },
+    /// Service message: new participants invited to a video chat.
+    VideoChatParticipantsInvited {
+        /// Information about the invited participants.
+        data: VideoChatParticipantsInvited,
+    },
+    /// Service message: data sent by a Web App.
+    WebAppData {
+        /// Information about the data.
+        data: WebAppData,
+    },
+    /// Message is Telegram Passport data.
+    PassportData {
+        /// Information about the data.
+        data: PassportData,
+    },
     #[doc(hidden)]
     Unknown { raw: RawMessage },
 }
@@ -236,6 +333,12 @@ impl Message {
 
         let reply_to_message = raw.reply_to_message.clone();
         let edit_date = raw.edit_date;
+        let sender_chat = raw.sender_chat.clone();
+        let via_bot = raw.via_bot.clone();
+        let has_protected_content = raw.has_protected_content.is_some();
+        let author_signature = raw.author_signature.clone();
+        let connected_website = raw.connected_website.clone();
+        let reply_markup = raw.reply_markup.clone();
 
         let forward = match (
             raw.forward_date,
@@ -248,6 +351,7 @@ impl Message {
             (Some(date), &Some(ref from), &None, None, &None) => Some(Forward {
                 date: date,
                 from: ForwardFrom::User { user: from.clone() },
+                signature: raw.forward_signature.clone(),
             }),
             (Some(date), &None, &Some(Chat::Channel(ref channel)), Some(message_id), &None) => {
                 Some(Forward {
@@ -256,6 +360,7 @@ impl Message {
                         channel: channel.clone(),
                         message_id: message_id,
                     },
+                    signature: raw.forward_signature.clone(),
                 })
             }
             (Some(date), &None, &None, None, &Some(ref sender_name)) => Some(Forward {
@@ -263,6 +368,7 @@ impl Message {
                 from: ForwardFrom::ChannelHiddenUser {
                     sender_name: sender_name.clone(),
                 },
+                signature: raw.forward_signature.clone(),
             }),
             (
                 Some(date),
@@ -278,6 +384,7 @@ impl Message {
                     chat_id: chat_id.clone(),
                     title: title.clone(),
                 },
+                signature: raw.forward_signature.clone(),
             }),
             _ => return Err(format!("invalid forward fields combination")),
         };
@@ -287,10 +394,16 @@ impl Message {
                 id: id.into(),
                 from: from,
                 date: date,
+                sender_chat,
                 chat: chat,
                 forward: forward,
                 reply_to_message: reply_to_message,
+                via_bot,
                 edit_date: edit_date,
+                has_protected_content,
+                author_signature,
+                connected_website,
+                reply_markup,
                 kind: kind,
             })
         };
@@ -309,6 +422,7 @@ impl Message {
                     return make_message(MessageKind::$variant {
                         data: val,
                         caption: raw.caption,
+                        caption_entities: raw.caption_entities.unwrap_or_else(Vec::new),
                     });
                 }
             }};
@@ -320,6 +434,7 @@ impl Message {
                     return make_message(MessageKind::$variant {
                         data: val,
                         caption: raw.caption,
+                        caption_entities: raw.caption_entities.unwrap_or_else(Vec::new),
                         media_group_id: raw.media_group_id,
                     });
                 }
@@ -334,6 +449,14 @@ impl Message {
             }};
         }
 
+        macro_rules! maybe_empty_field {
+            ($name:ident, $variant:ident) => {{
+                if raw.$name.is_some() {
+                    return make_message(MessageKind::$variant);
+                }
+            }};
+        }
+
         if let Some(text) = raw.text {
             let entities = raw.entities.unwrap_or_else(Vec::new);
             return make_message(MessageKind::Text {
@@ -365,6 +488,24 @@ impl Message {
         maybe_field!(migrate_to_chat_id, MigrateToChatId);
         maybe_field!(migrate_from_chat_id, MigrateFromChatId);
         maybe_field!(pinned_message, PinnedMessage);
+        maybe_field!(invoice, Invoice);
+        maybe_field!(successful_payment, SuccessfulPayment);
+        maybe_field!(game, Game);
+        maybe_field!(dice, Dice);
+        maybe_field!(proximity_alert_triggered, ProximityAlertTriggered);
+        maybe_field!(
+            message_auto_delete_timer_changed,
+            MessageAutoDeleteTimerChanged
+        );
+        maybe_field!(video_chat_scheduled, VideoChatScheduled);
+        maybe_empty_field!(video_chat_started, VideoChatStarted);
+        maybe_field!(video_chat_ended, VideoChatEnded);
+        maybe_field!(
+            video_chat_participants_invited,
+            VideoChatParticipantsInvited
+        );
+        maybe_field!(web_app_data, WebAppData);
+        maybe_field!(passport_data, PassportData);
 
         make_message(MessageKind::Unknown { raw: raw })
     }
@@ -391,6 +532,12 @@ impl ChannelPost {
         };
         let reply_to_message = raw.reply_to_message.clone();
         let edit_date = raw.edit_date;
+        let sender_chat = raw.sender_chat.clone();
+        let via_bot = raw.via_bot.clone();
+        let has_protected_content = raw.has_protected_content.is_some();
+        let author_signature = raw.author_signature.clone();
+        let connected_website = raw.connected_website.clone();
+        let reply_markup = raw.reply_markup.clone();
 
         let forward = match (
             raw.forward_date,
@@ -403,6 +550,7 @@ impl ChannelPost {
             (Some(date), &Some(ref from), &None, None, &None) => Some(Forward {
                 date: date,
                 from: ForwardFrom::User { user: from.clone() },
+                signature: raw.forward_signature.clone(),
             }),
             (Some(date), &None, &Some(Chat::Channel(ref channel)), Some(message_id), &None) => {
                 Some(Forward {
@@ -411,6 +559,7 @@ impl ChannelPost {
                         channel: channel.clone(),
                         message_id: message_id,
                     },
+                    signature: raw.forward_signature.clone(),
                 })
             }
             (Some(date), &None, &None, None, &Some(ref sender_name)) => Some(Forward {
@@ -418,6 +567,7 @@ impl ChannelPost {
                 from: ForwardFrom::ChannelHiddenUser {
                     sender_name: sender_name.clone(),
                 },
+                signature: raw.forward_signature.clone(),
             }),
             (
                 Some(date),
@@ -433,6 +583,7 @@ impl ChannelPost {
                     chat_id: chat_id.clone(),
                     title: title.clone(),
                 },
+                signature: raw.forward_signature.clone(),
             }),
             _ => return Err(format!("invalid forward fields combination")),
         };
@@ -441,10 +592,16 @@ impl ChannelPost {
             Ok(ChannelPost {
                 id: id.into(),
                 date: date,
+                sender_chat,
                 chat: chat,
                 forward: forward,
                 reply_to_message: reply_to_message,
+                via_bot,
                 edit_date: edit_date,
+                has_protected_content,
+                author_signature,
+                connected_website,
+                reply_markup,
                 kind: kind,
             })
         };
@@ -463,6 +620,7 @@ impl ChannelPost {
                     return make_message(MessageKind::$variant {
                         data: val,
                         caption: raw.caption,
+                        caption_entities: raw.caption_entities.unwrap_or_else(Vec::new),
                     });
                 }
             }};
@@ -474,6 +632,7 @@ impl ChannelPost {
                     return make_message(MessageKind::$variant {
                         data: val,
                         caption: raw.caption,
+                        caption_entities: raw.caption_entities.unwrap_or_else(Vec::new),
                         media_group_id: raw.media_group_id,
                     });
                 }
@@ -488,6 +647,14 @@ impl ChannelPost {
             }};
         }
 
+        macro_rules! maybe_empty_field {
+            ($name:ident, $variant:ident) => {{
+                if raw.$name.is_some() {
+                    return make_message(MessageKind::$variant);
+                }
+            }};
+        }
+
         if let Some(text) = raw.text {
             let entities = raw.entities.unwrap_or_else(Vec::new);
             return make_message(MessageKind::Text {
@@ -519,6 +686,24 @@ impl ChannelPost {
         maybe_field!(migrate_to_chat_id, MigrateToChatId);
         maybe_field!(migrate_from_chat_id, MigrateFromChatId);
         maybe_field!(pinned_message, PinnedMessage);
+        maybe_field!(invoice, Invoice);
+        maybe_field!(successful_payment, SuccessfulPayment);
+        maybe_field!(game, Game);
+        maybe_field!(dice, Dice);
+        maybe_field!(proximity_alert_triggered, ProximityAlertTriggered);
+        maybe_field!(
+            message_auto_delete_timer_changed,
+            MessageAutoDeleteTimerChanged
+        );
+        maybe_field!(video_chat_scheduled, VideoChatScheduled);
+        maybe_empty_field!(video_chat_started, VideoChatStarted);
+        maybe_field!(video_chat_ended, VideoChatEnded);
+        maybe_field!(
+            video_chat_participants_invited,
+            VideoChatParticipantsInvited
+        );
+        maybe_field!(web_app_data, WebAppData);
+        maybe_field!(passport_data, PassportData);
 
         make_message(MessageKind::Unknown { raw: raw })
     }
@@ -565,6 +750,8 @@ pub struct RawMessage {
     pub message_id: Integer,
     /// Sender, can be empty for messages sent to channels.
     pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
     /// Date the message was sent in Unix time.
     pub date: Integer,
     /// Conversation the message belongs to.
@@ -575,15 +762,24 @@ pub struct RawMessage {
     pub forward_from_chat: Option<Chat>,
     /// For forwarded channel posts, identifier of the original message in the channel.
     pub forward_from_message_id: Option<Integer>,
+    /// For messages forwarded from channels, signature of the post author if present.
+    pub forward_signature: Option<String>,
     /// For forwarded messages, date the original message was sent in Unix time.
     pub forward_date: Option<Integer>,
     /// For replies, the original message. Note that the Message object in this field will not
     /// contain further reply_to_message fields even if it itself is a reply.
     pub reply_to_message: Option<Box<MessageOrChannelPost>>,
+    /// Bot through which the message was sent.
+    pub via_bot: Option<User>,
     /// Date the message was last edited in Unix time.
     pub edit_date: Option<Integer>,
+    /// True, if the message can't be forwarded.
+    pub has_protected_content: Option<True>,
     /// The unique identifier of a media message group this message belongs to.
     pub media_group_id: Option<String>,
+    /// Signature of the post author for messages in channels, or the custom title
+    /// of an anonymous group administrator.
+    pub author_signature: Option<String>,
     /// For text messages, the actual UTF-8 text of the message, 0-4096 characters.
     pub text: Option<String>,
     /// For text messages, special entities like usernames, URLs, bot commands, etc.
@@ -593,7 +789,8 @@ pub struct RawMessage {
     pub audio: Option<Audio>,
     /// Message is a general file, information about the file.
     pub document: Option<Document>,
-    // pub game: Option<Game>,
+    /// Message is a game, information about the game.
+    pub game: Option<Game>,
     /// Message is a photo, available sizes of the photo.
     pub photo: Option<Vec<PhotoSize>>,
     /// Message is a sticker, information about the sticker.
@@ -606,6 +803,9 @@ pub struct RawMessage {
     pub video_note: Option<VideoNote>,
     /// Caption for the document, photo or video, 0-200 characters.
     pub caption: Option<String>,
+    /// For messages with a caption, special entities like usernames, URLs, bot commands, etc.
+    /// that appear in the caption.
+    pub caption_entities: Option<Vec<MessageEntity>>,
     /// Message is a shared contact, information about the contact.
     pub contact: Option<Contact>,
     /// Message is a shared location, information about the location.
@@ -645,8 +845,46 @@ pub struct RawMessage {
     /// Specified message was pinned. Note that the Message object in this field will not contain
     /// further reply_to_message fields even if it is itself a reply.
     pub pinned_message: Option<Box<MessageOrChannelPost>>,
+    /// Message is an invoice for a payment, information about the invoice.
+    pub invoice: Option<Invoice>,
+    /// Message is a service message about a successful payment, information about the payment.
+    pub successful_payment: Option<SuccessfulPayment>,
     /// Forward from channel by a hidden user.
     pub forward_sender_name: Option<String>,
+    /// Message is a dice with random value.
+    pub dice: Option<Dice>,
+    /// The domain name of the website on which the user has logged in.
+    pub connected_website: Option<String>,
+    /// Service message. A user in the chat triggered another user's proximity alert
+    /// while sharing Live Location.
+    pub proximity_alert_triggered: Option<ProximityAlertTriggered>,
+    /// Service message: auto-delete timer settings changed in the chat.
+    pub message_auto_delete_timer_changed: Option<MessageAutoDeleteTimerChanged>,
+    /// Service message: video chat scheduled.
+    #[serde(alias = "voice_chat_scheduled")]
+    pub video_chat_scheduled: Option<VideoChatScheduled>,
+    /// Service message: video chat started.
+    #[serde(alias = "voice_chat_started")]
+    pub video_chat_started: Option<VideoChatStarted>,
+    /// Service message: video chat ended.
+    #[serde(alias = "voice_chat_ended")]
+    pub video_chat_ended: Option<VideoChatEnded>,
+    /// Service message: new participants invited to a video chat.
+    #[serde(alias = "voice_chat_participants_invited")]
+    pub video_chat_participants_invited: Option<VideoChatParticipantsInvited>,
+    /// Service message: data sent by a Web App.
+    pub web_app_data: Option<WebAppData>,
+    /// Telegram Passport data.
+    pub passport_data: Option<PassportData>,
+    /// Inline keyboard attached to the message.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// This object represents a unique message identifier. Directly mapped.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct RawMessageId {
+    /// Unique message identifier.
+    pub message_id: MessageId,
 }
 
 /// This object represents one special entity in a text message.
@@ -661,6 +899,64 @@ pub struct MessageEntity {
     pub kind: MessageEntityKind,
 }
 
+impl MessageEntity {
+    pub fn new(kind: MessageEntityKind, offset: Integer, length: Integer) -> Self {
+        MessageEntity {
+            offset,
+            length,
+            kind,
+        }
+    }
+
+    /// Byte range of the entity inside `text`.
+    ///
+    /// Entity offsets are measured in UTF-16 code units, so they can't be used to slice
+    /// a `str` directly. Returns `None` if the entity doesn't fit into `text` or
+    /// its bounds split a character.
+    pub fn byte_range(&self, text: &str) -> Option<Range<usize>> {
+        let start = utf16_to_byte_offset(text, self.offset)?;
+        let end = utf16_to_byte_offset(text, self.offset.checked_add(self.length)?)?;
+        if start <= end {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+
+    /// The part of `text` covered by the entity.
+    pub fn extract<'a>(&self, text: &'a str) -> Option<&'a str> {
+        self.byte_range(text).map(|range| &text[range])
+    }
+}
+
+/// Converts an offset in UTF-16 code units into a byte offset inside `text`.
+/// Returns `None` if the offset is out of bounds or points inside a character.
+pub(crate) fn utf16_to_byte_offset(text: &str, offset: Integer) -> Option<usize> {
+    if offset < 0 {
+        return None;
+    }
+    let mut utf16_offset = 0;
+    for (byte_offset, c) in text.char_indices() {
+        if utf16_offset == offset {
+            return Some(byte_offset);
+        }
+        if utf16_offset > offset {
+            return None;
+        }
+        utf16_offset += c.len_utf16() as Integer;
+    }
+    if utf16_offset == offset {
+        Some(text.len())
+    } else {
+        None
+    }
+}
+
+/// Length of `text` in UTF-16 code units, as used by entity offsets and lengths.
+pub(crate) fn utf16_len(text: &str) -> Integer {
+    text.encode_utf16().count() as Integer
+}
+
 /// Kind of the entity.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum MessageEntityKind {
@@ -671,10 +967,18 @@ pub enum MessageEntityKind {
     Email,
     Bold,
     Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Cashtag,
+    PhoneNumber,
     Code,
-    Pre,
+    /// Monowidth block, with the programming language of the entity text.
+    Pre(Option<String>),
     TextLink(String), // TODO(knsd) URL?
     TextMention(User),
+    /// Inline custom emoji sticker, with the unique identifier of the custom emoji.
+    CustomEmoji(String),
     #[doc(hidden)]
     Unknown(RawMessageEntity),
 }
@@ -708,10 +1012,16 @@ impl<'de> Deserialize<'de> for MessageEntity {
             "email" => Email,
             "bold" => Bold,
             "italic" => Italic,
+            "underline" => Underline,
+            "strikethrough" => Strikethrough,
+            "spoiler" => Spoiler,
+            "cashtag" => Cashtag,
+            "phone_number" => PhoneNumber,
             "code" => Code,
-            "pre" => Pre,
+            "pre" => Pre(raw.language),
             "text_link" => TextLink(required_field!(url)),
             "text_mention" => TextMention(required_field!(user)),
+            "custom_emoji" => CustomEmoji(required_field!(custom_emoji_id)),
             _ => Unknown(raw),
         };
 
@@ -723,13 +1033,90 @@ impl<'de> Deserialize<'de> for MessageEntity {
     }
 }
 
+impl Serialize for MessageEntity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use self::MessageEntityKind::*;
+
+        #[derive(Serialize)]
+        struct RawMessageEntityRef<'a> {
+            #[serde(rename = "type")]
+            type_: &'a str,
+            offset: Integer,
+            length: Integer,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            url: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            user: Option<&'a User>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            language: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            custom_emoji_id: Option<&'a str>,
+        }
+
+        let mut raw = RawMessageEntityRef {
+            type_: "",
+            offset: self.offset,
+            length: self.length,
+            url: None,
+            user: None,
+            language: None,
+            custom_emoji_id: None,
+        };
+
+        raw.type_ = match self.kind {
+            Mention => "mention",
+            Hashtag => "hashtag",
+            BotCommand => "bot_command",
+            Url => "url",
+            Email => "email",
+            Bold => "bold",
+            Italic => "italic",
+            Underline => "underline",
+            Strikethrough => "strikethrough",
+            Spoiler => "spoiler",
+            Cashtag => "cashtag",
+            PhoneNumber => "phone_number",
+            Code => "code",
+            Pre(ref language) => {
+                raw.language = language.as_deref();
+                "pre"
+            }
+            TextLink(ref url) => {
+                raw.url = Some(url);
+                "text_link"
+            }
+            TextMention(ref user) => {
+                raw.user = Some(user);
+                "text_mention"
+            }
+            CustomEmoji(ref custom_emoji_id) => {
+                raw.custom_emoji_id = Some(custom_emoji_id);
+                "custom_emoji"
+            }
+            Unknown(ref unknown) => {
+                raw.url = unknown.url.as_deref();
+                raw.user = unknown.user.as_ref();
+                raw.language = unknown.language.as_deref();
+                raw.custom_emoji_id = unknown.custom_emoji_id.as_deref();
+                &unknown.type_
+            }
+        };
+
+        raw.serialize(serializer)
+    }
+}
+
 /// This object represents one special entity in a text message.
 /// For example, hashtags, usernames, URLs, etc. Directly mapped.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
 pub struct RawMessageEntity {
-    /// Type of the entity. Can be mention (@username), hashtag, bot_command, url, email,
-    /// bold (bold text), italic (italic text), code (monowidth string), pre (monowidth block),
-    /// text_link (for clickable text URLs), text_mention (for users without usernames).
+    /// Type of the entity. Can be mention (@username), hashtag, cashtag, bot_command, url, email,
+    /// phone_number, bold (bold text), italic (italic text), underline, strikethrough, spoiler,
+    /// code (monowidth string), pre (monowidth block), text_link (for clickable text URLs),
+    /// text_mention (for users without usernames), custom_emoji (for inline custom emoji stickers).
     #[serde(rename = "type")]
     pub type_: String,
     /// Offset in UTF-16 code units to the start of the entity.
@@ -740,6 +1127,10 @@ pub struct RawMessageEntity {
     pub url: Option<String>,
     /// For “text_mention” only, the mentioned user.
     pub user: Option<User>,
+    /// For “pre” only, the programming language of the entity text.
+    pub language: Option<String>,
+    /// For “custom_emoji” only, unique identifier of the custom emoji.
+    pub custom_emoji_id: Option<String>,
 }
 
 /// This object represents one size of a photo or a file / sticker thumbnail.
@@ -787,6 +1178,30 @@ pub struct Document {
     pub file_size: Option<Integer>,
 }
 
+/// This object represents an animation file (GIF or H.264/MPEG-4 AVC video without sound).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Animation {
+    /// Identifier for this file, which can be used to download or reuse the file.
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots.
+    /// Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// Video width as defined by sender.
+    pub width: Integer,
+    /// Video height as defined by sender.
+    pub height: Integer,
+    /// Duration of the video in seconds as defined by sender.
+    pub duration: Integer,
+    /// Animation thumbnail as defined by sender.
+    pub thumb: Option<PhotoSize>,
+    /// Original animation filename as defined by sender.
+    pub file_name: Option<String>,
+    /// MIME type of the file as defined by sender.
+    pub mime_type: Option<String>,
+    /// File size.
+    pub file_size: Option<Integer>,
+}
+
 /// This object represents a sticker.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
 pub struct Sticker {
@@ -877,6 +1292,124 @@ pub struct Location {
     pub latitude: Float,
 }
 
+/// This object represents an animated emoji that displays a random value.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Dice {
+    /// Emoji on which the dice throw animation is based.
+    pub emoji: DiceEmoji,
+    /// Value of the dice, 1-6 for “🎲”, “🎯” and “🎳” base emoji,
+    /// 1-5 for “🏀” and “⚽” base emoji, 1-64 for “🎰” base emoji.
+    pub value: Integer,
+}
+
+/// Emoji on which the dice throw animation is based.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum DiceEmoji {
+    /// “🎲”
+    Dice,
+    /// “🎯”
+    Darts,
+    /// “🏀”
+    Basketball,
+    /// “⚽”
+    Football,
+    /// “🎳”
+    Bowling,
+    /// “🎰”
+    SlotMachine,
+    #[doc(hidden)]
+    Unknown(String),
+}
+
+impl DiceEmoji {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DiceEmoji::Dice => "🎲",
+            DiceEmoji::Darts => "🎯",
+            DiceEmoji::Basketball => "🏀",
+            DiceEmoji::Football => "⚽",
+            DiceEmoji::Bowling => "🎳",
+            DiceEmoji::SlotMachine => "🎰",
+            DiceEmoji::Unknown(ref emoji) => emoji,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DiceEmoji {
+    fn deserialize<D>(deserializer: D) -> Result<DiceEmoji, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: String = Deserialize::deserialize(deserializer)?;
+
+        Ok(match raw.as_str() {
+            "🎲" => DiceEmoji::Dice,
+            "🎯" => DiceEmoji::Darts,
+            "🏀" => DiceEmoji::Basketball,
+            "⚽" => DiceEmoji::Football,
+            "🎳" => DiceEmoji::Bowling,
+            "🎰" => DiceEmoji::SlotMachine,
+            _ => DiceEmoji::Unknown(raw),
+        })
+    }
+}
+
+impl Serialize for DiceEmoji {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// This object represents the content of a service message, sent whenever a user in the chat
+/// triggers a proximity alert set by another user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ProximityAlertTriggered {
+    /// User that triggered the alert.
+    pub traveler: User,
+    /// User that set the alert.
+    pub watcher: User,
+    /// The distance between the users.
+    pub distance: Integer,
+}
+
+/// This object represents a service message about a change in auto-delete timer settings.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct MessageAutoDeleteTimerChanged {
+    /// New auto-delete time for messages in the chat; in seconds.
+    pub message_auto_delete_time: Integer,
+}
+
+/// This object represents a service message about a video chat scheduled in the chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatScheduled {
+    /// Point in time (Unix timestamp) when the video chat is supposed to be started
+    /// by a chat administrator.
+    pub start_date: Integer,
+}
+
+/// This object represents a service message about a video chat started in the chat.
+/// Currently holds no information.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatStarted {}
+
+/// This object represents a service message about a video chat ended in the chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatEnded {
+    /// Video chat duration in seconds.
+    pub duration: Integer,
+}
+
+/// This object represents a service message about new members invited to a video chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatParticipantsInvited {
+    /// New members that were invited to the video chat.
+    #[serde(default)]
+    pub users: Vec<User>,
+}
+
 /// This object represents a venue.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
 pub struct Venue {
@@ -1,13 +1,22 @@
+pub mod bot_command;
+pub mod callback_data;
 pub mod callback_query;
 pub mod chat;
+pub mod chat_full_info;
 pub mod chat_invite_link;
+pub mod chat_join_request;
 pub mod chat_member;
 pub mod chat_member_update;
+pub mod chat_permissions;
 pub mod chosen_inline_result;
+pub mod game;
 pub mod inline_query;
 pub mod inline_query_result;
 pub mod input_file;
+pub mod login_url;
 pub mod message;
+pub mod passport;
+pub mod payments;
 pub mod pre_checkout_query;
 pub mod primitive;
 pub mod refs;
@@ -16,17 +25,27 @@ pub mod response_parameters;
 pub mod shipping_query;
 pub mod text;
 pub mod update;
+pub mod web_app;
 
+pub use self::bot_command::*;
+pub use self::callback_data::*;
 pub use self::callback_query::*;
 pub use self::chat::*;
+pub use self::chat_full_info::*;
 pub use self::chat_invite_link::*;
+pub use self::chat_join_request::*;
 pub use self::chat_member::*;
 pub use self::chat_member_update::*;
+pub use self::chat_permissions::*;
 pub use self::chosen_inline_result::*;
+pub use self::game::*;
 pub use self::inline_query::*;
 pub use self::inline_query_result::*;
 pub use self::input_file::*;
+pub use self::login_url::*;
 pub use self::message::*;
+pub use self::passport::*;
+pub use self::payments::*;
 pub use self::pre_checkout_query::*;
 pub use self::primitive::*;
 pub use self::refs::*;
@@ -35,3 +54,4 @@ pub use self::response_parameters::*;
 pub use self::shipping_query::*;
 pub use self::text::*;
 pub use self::update::*;
+pub use self::web_app::*;
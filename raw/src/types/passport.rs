@@ -0,0 +1,309 @@
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::types::*;
+
+/// Describes Telegram Passport data shared with the bot by the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct PassportData {
+    /// Array with information about documents and other Telegram Passport elements
+    /// that was shared with the bot.
+    pub data: Vec<EncryptedPassportElement>,
+    /// Encrypted credentials required to decrypt the data.
+    pub credentials: EncryptedCredentials,
+}
+
+/// This object represents a file uploaded to Telegram Passport. Currently all Telegram Passport
+/// files are in JPEG format when decrypted and don't exceed 10MB.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct PassportFile {
+    /// Identifier for this file, which can be used to download or reuse the file.
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time
+    /// and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// File size in bytes.
+    pub file_size: Integer,
+    /// Unix time when the file was uploaded.
+    pub file_date: Integer,
+}
+
+/// Describes documents or other Telegram Passport elements shared with the bot by the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct EncryptedPassportElement {
+    /// Element type.
+    #[serde(rename = "type")]
+    pub kind: PassportElementType,
+    /// Base64-encoded encrypted Telegram Passport element data provided by the user,
+    /// available for “personal_details”, “passport”, “driver_license”, “identity_card”,
+    /// “internal_passport” and “address” types.
+    pub data: Option<String>,
+    /// User's verified phone number, available only for “phone_number” type.
+    pub phone_number: Option<String>,
+    /// User's verified email address, available only for “email” type.
+    pub email: Option<String>,
+    /// Array of encrypted files with documents provided by the user, available for
+    /// “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”
+    /// and “temporary_registration” types.
+    pub files: Option<Vec<PassportFile>>,
+    /// Encrypted file with the front side of the document, provided by the user.
+    pub front_side: Option<PassportFile>,
+    /// Encrypted file with the reverse side of the document, provided by the user.
+    pub reverse_side: Option<PassportFile>,
+    /// Encrypted file with the selfie of the user holding a document, provided by the user.
+    pub selfie: Option<PassportFile>,
+    /// Array of encrypted files with translated versions of documents provided by the user.
+    pub translation: Option<Vec<PassportFile>>,
+    /// Base64-encoded element hash for using in `PassportElementError::Unspecified`.
+    pub hash: String,
+}
+
+/// Describes data required for decrypting and authenticating `EncryptedPassportElement`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct EncryptedCredentials {
+    /// Base64-encoded encrypted JSON-serialized data with unique user's payload,
+    /// data hashes and secrets required for `EncryptedPassportElement` decryption
+    /// and authentication.
+    pub data: String,
+    /// Base64-encoded data hash for data authentication.
+    pub hash: String,
+    /// Base64-encoded secret, encrypted with the bot's public RSA key,
+    /// required for data decryption.
+    pub secret: String,
+}
+
+/// Type of a Telegram Passport element.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum PassportElementType {
+    PersonalDetails,
+    Passport,
+    DriverLicense,
+    IdentityCard,
+    InternalPassport,
+    Address,
+    UtilityBill,
+    BankStatement,
+    RentalAgreement,
+    PassportRegistration,
+    TemporaryRegistration,
+    PhoneNumber,
+    Email,
+    #[doc(hidden)]
+    Unknown(String),
+}
+
+impl PassportElementType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PassportElementType::PersonalDetails => "personal_details",
+            PassportElementType::Passport => "passport",
+            PassportElementType::DriverLicense => "driver_license",
+            PassportElementType::IdentityCard => "identity_card",
+            PassportElementType::InternalPassport => "internal_passport",
+            PassportElementType::Address => "address",
+            PassportElementType::UtilityBill => "utility_bill",
+            PassportElementType::BankStatement => "bank_statement",
+            PassportElementType::RentalAgreement => "rental_agreement",
+            PassportElementType::PassportRegistration => "passport_registration",
+            PassportElementType::TemporaryRegistration => "temporary_registration",
+            PassportElementType::PhoneNumber => "phone_number",
+            PassportElementType::Email => "email",
+            PassportElementType::Unknown(ref kind) => kind,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PassportElementType {
+    fn deserialize<D>(deserializer: D) -> Result<PassportElementType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: String = Deserialize::deserialize(deserializer)?;
+
+        Ok(match raw.as_str() {
+            "personal_details" => PassportElementType::PersonalDetails,
+            "passport" => PassportElementType::Passport,
+            "driver_license" => PassportElementType::DriverLicense,
+            "identity_card" => PassportElementType::IdentityCard,
+            "internal_passport" => PassportElementType::InternalPassport,
+            "address" => PassportElementType::Address,
+            "utility_bill" => PassportElementType::UtilityBill,
+            "bank_statement" => PassportElementType::BankStatement,
+            "rental_agreement" => PassportElementType::RentalAgreement,
+            "passport_registration" => PassportElementType::PassportRegistration,
+            "temporary_registration" => PassportElementType::TemporaryRegistration,
+            "phone_number" => PassportElementType::PhoneNumber,
+            "email" => PassportElementType::Email,
+            _ => PassportElementType::Unknown(raw),
+        })
+    }
+}
+
+impl Serialize for PassportElementType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// This object represents an error in the Telegram Passport element which was submitted
+/// that should be resolved by the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(tag = "source")]
+pub enum PassportElementError {
+    /// Issue in one of the data fields that was provided by the user.
+    #[serde(rename = "data")]
+    DataField {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        field_name: String,
+        data_hash: String,
+        message: String,
+    },
+    /// Issue with the front side of a document.
+    #[serde(rename = "front_side")]
+    FrontSide {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// Issue with the reverse side of a document.
+    #[serde(rename = "reverse_side")]
+    ReverseSide {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// Issue with the selfie with a document.
+    #[serde(rename = "selfie")]
+    Selfie {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// Issue with a document scan.
+    #[serde(rename = "file")]
+    File {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// Issue with a list of scans.
+    #[serde(rename = "files")]
+    Files {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// Issue with one of the files that constitute the translation of a document.
+    #[serde(rename = "translation_file")]
+    TranslationFile {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// Issue with the translated version of a document.
+    #[serde(rename = "translation_files")]
+    TranslationFiles {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// Issue in an unspecified place.
+    #[serde(rename = "unspecified")]
+    Unspecified {
+        #[serde(rename = "type")]
+        kind: PassportElementType,
+        element_hash: String,
+        message: String,
+    },
+}
+
+/// Decrypted contents of `EncryptedCredentials`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Credentials {
+    /// Credentials for encrypted data.
+    pub secure_data: SecureData,
+    /// Bot-specified nonce, make sure it matches the one from the request.
+    pub nonce: String,
+}
+
+/// Credentials for the encrypted Telegram Passport elements.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Deserialize)]
+pub struct SecureData {
+    pub personal_details: Option<SecureValue>,
+    pub passport: Option<SecureValue>,
+    pub internal_passport: Option<SecureValue>,
+    pub driver_license: Option<SecureValue>,
+    pub identity_card: Option<SecureValue>,
+    pub address: Option<SecureValue>,
+    pub utility_bill: Option<SecureValue>,
+    pub bank_statement: Option<SecureValue>,
+    pub rental_agreement: Option<SecureValue>,
+    pub passport_registration: Option<SecureValue>,
+    pub temporary_registration: Option<SecureValue>,
+}
+
+impl SecureData {
+    /// Credentials for the element of `kind`.
+    pub fn get(&self, kind: &PassportElementType) -> Option<&SecureValue> {
+        match kind {
+            PassportElementType::PersonalDetails => self.personal_details.as_ref(),
+            PassportElementType::Passport => self.passport.as_ref(),
+            PassportElementType::InternalPassport => self.internal_passport.as_ref(),
+            PassportElementType::DriverLicense => self.driver_license.as_ref(),
+            PassportElementType::IdentityCard => self.identity_card.as_ref(),
+            PassportElementType::Address => self.address.as_ref(),
+            PassportElementType::UtilityBill => self.utility_bill.as_ref(),
+            PassportElementType::BankStatement => self.bank_statement.as_ref(),
+            PassportElementType::RentalAgreement => self.rental_agreement.as_ref(),
+            PassportElementType::PassportRegistration => self.passport_registration.as_ref(),
+            PassportElementType::TemporaryRegistration => self.temporary_registration.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Credentials for the data and files of one Telegram Passport element.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Deserialize)]
+pub struct SecureValue {
+    /// Credentials for encrypted Telegram Passport data.
+    pub data: Option<DataCredentials>,
+    /// Credentials for an encrypted document's front side.
+    pub front_side: Option<FileCredentials>,
+    /// Credentials for an encrypted document's reverse side.
+    pub reverse_side: Option<FileCredentials>,
+    /// Credentials for an encrypted selfie of the user with a document.
+    pub selfie: Option<FileCredentials>,
+    /// Credentials for an encrypted translation of the document.
+    pub translation: Option<Vec<FileCredentials>>,
+    /// Credentials for encrypted files.
+    pub files: Option<Vec<FileCredentials>>,
+}
+
+/// Credentials required to decrypt `EncryptedPassportElement::data`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct DataCredentials {
+    /// Base64-encoded checksum of encrypted data.
+    pub data_hash: String,
+    /// Base64-encoded secret of encrypted data.
+    pub secret: String,
+}
+
+/// Credentials required to decrypt a `PassportFile`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct FileCredentials {
+    /// Base64-encoded checksum of encrypted file.
+    pub file_hash: String,
+    /// Base64-encoded secret of encrypted file.
+    pub secret: String,
+}
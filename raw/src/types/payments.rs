@@ -0,0 +1,85 @@
+use crate::types::*;
+
+/// This object represents a portion of the price for goods or services.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct LabeledPrice {
+    /// Portion label
+    pub label: String,
+    /// Price of the product in the smallest units of the currency (integer, not float/double).
+    /// For example, for a price of US$ 1.45 pass amount = 145.
+    pub amount: Integer,
+}
+
+impl LabeledPrice {
+    pub fn new<L>(label: L, amount: Integer) -> Self
+    where
+        L: Into<String>,
+    {
+        LabeledPrice {
+            label: label.into(),
+            amount,
+        }
+    }
+}
+
+/// This object contains basic information about an invoice.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Invoice {
+    /// Product name
+    pub title: String,
+    /// Product description
+    pub description: String,
+    /// Unique bot deep-linking parameter that can be used to generate this invoice
+    pub start_parameter: String,
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not float/double).
+    pub total_amount: Integer,
+}
+
+/// This object represents one shipping option.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct ShippingOption {
+    id: String,
+    title: String,
+    prices: Vec<LabeledPrice>,
+}
+
+impl ShippingOption {
+    pub fn new<I, T>(id: I, title: T) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        ShippingOption {
+            id: id.into(),
+            title: title.into(),
+            prices: Vec::new(),
+        }
+    }
+
+    /// Add a price portion to the shipping option.
+    pub fn add_price(&mut self, price: LabeledPrice) -> &mut Self {
+        self.prices.push(price);
+        self
+    }
+}
+
+/// This object contains basic information about a successful payment.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct SuccessfulPayment {
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not float/double).
+    pub total_amount: Integer,
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+    /// Optional. Identifier of the shipping option chosen by the user
+    pub shipping_option_id: Option<String>,
+    /// Optional. Order info provided by the user
+    pub order_info: Option<OrderInfo>,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+    /// Provider payment identifier
+    pub provider_payment_charge_id: String,
+}
@@ -4,7 +4,7 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
 pub struct PreCheckoutQuery {
     /// Unique query identifier
-    pub id: CallbackQueryId,
+    pub id: PreCheckoutQueryId,
     /// User who sent the query
     pub from: User,
     /// Three-letter ISO 4217 currency code
@@ -130,6 +130,12 @@ impl ToChatRef for Chat {
     }
 }
 
+impl ToChatRef for ChatFullInfo {
+    fn to_chat_ref(&self) -> ChatRef {
+        self.chat.to_chat_ref()
+    }
+}
+
 impl ToChatRef for MessageChat {
     fn to_chat_ref(&self) -> ChatRef {
         self.id().to_chat_ref()
@@ -161,6 +167,45 @@ impl ToChatRef for Forward {
     }
 }
 
+/// Get `ChatId` from the type reference.
+pub trait ToChatId {
+    fn to_chat_id(&self) -> ChatId;
+}
+
+impl<S> ToChatId for S
+where
+    S: Deref,
+    S::Target: ToChatId,
+{
+    fn to_chat_id(&self) -> ChatId {
+        self.deref().to_chat_id()
+    }
+}
+
+impl ToChatId for ChatId {
+    fn to_chat_id(&self) -> ChatId {
+        *self
+    }
+}
+
+impl ToChatId for ChannelId {
+    fn to_chat_id(&self) -> ChatId {
+        (*self).into()
+    }
+}
+
+impl ToChatId for Chat {
+    fn to_chat_id(&self) -> ChatId {
+        self.id()
+    }
+}
+
+impl ToChatId for Channel {
+    fn to_chat_id(&self) -> ChatId {
+        self.id.into()
+    }
+}
+
 impl Serialize for ChatRef {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -344,11 +389,13 @@ macro_rules! file_id_impls {
 
 file_id_impls!(PhotoSize);
 file_id_impls!(Audio);
+file_id_impls!(Animation);
 file_id_impls!(Document);
 file_id_impls!(Sticker);
 file_id_impls!(Video);
 file_id_impls!(Voice);
 file_id_impls!(VideoNote);
+file_id_impls!(PassportFile);
 
 /// Unique file identifier reference.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -406,3 +453,65 @@ pub struct CallbackQueryId(String);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct InlineQueryId(String);
+
+/// Get `ShippingQueryId` from the type reference.
+pub trait ToShippingQueryId {
+    fn to_shipping_query_id(&self) -> ShippingQueryId;
+}
+
+impl<S> ToShippingQueryId for S
+where
+    S: Deref,
+    S::Target: ToShippingQueryId,
+{
+    fn to_shipping_query_id(&self) -> ShippingQueryId {
+        self.deref().to_shipping_query_id()
+    }
+}
+
+impl ToShippingQueryId for ShippingQueryId {
+    fn to_shipping_query_id(&self) -> ShippingQueryId {
+        self.clone()
+    }
+}
+
+impl ToShippingQueryId for ShippingQuery {
+    fn to_shipping_query_id(&self) -> ShippingQueryId {
+        self.id.clone()
+    }
+}
+
+/// Unique identifier for ShippingQuery.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ShippingQueryId(String);
+
+/// Get `PreCheckoutQueryId` from the type reference.
+pub trait ToPreCheckoutQueryId {
+    fn to_pre_checkout_query_id(&self) -> PreCheckoutQueryId;
+}
+
+impl<S> ToPreCheckoutQueryId for S
+where
+    S: Deref,
+    S::Target: ToPreCheckoutQueryId,
+{
+    fn to_pre_checkout_query_id(&self) -> PreCheckoutQueryId {
+        self.deref().to_pre_checkout_query_id()
+    }
+}
+
+impl ToPreCheckoutQueryId for PreCheckoutQueryId {
+    fn to_pre_checkout_query_id(&self) -> PreCheckoutQueryId {
+        self.clone()
+    }
+}
+
+impl ToPreCheckoutQueryId for PreCheckoutQuery {
+    fn to_pre_checkout_query_id(&self) -> PreCheckoutQueryId {
+        self.id.clone()
+    }
+}
+
+/// Unique identifier for PreCheckoutQuery.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PreCheckoutQueryId(String);
@@ -1,5 +1,9 @@
+use std::cmp::Ordering;
 use std::ops::Not;
 
+use serde::de::{Deserialize, DeserializeOwned, Deserializer, Error};
+use serde_json::{Map, Value};
+
 use crate::types::*;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
@@ -49,6 +53,8 @@ pub struct ReplyKeyboardMarkup {
     resize_keyboard: bool,
     #[serde(skip_serializing_if = "Not::not")]
     one_time_keyboard: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_field_placeholder: Option<String>,
     #[serde(skip_serializing_if = "Not::not")]
     selective: bool,
 }
@@ -59,6 +65,7 @@ impl ReplyKeyboardMarkup {
             keyboard: Vec::new(),
             resize_keyboard: false,
             one_time_keyboard: false,
+            input_field_placeholder: None,
             selective: false,
         }
     }
@@ -88,6 +95,13 @@ impl ReplyKeyboardMarkup {
         self
     }
 
+    /// The placeholder to be shown in the input field when the keyboard is active;
+    /// 1-64 characters.
+    pub fn input_field_placeholder<T: Into<String>>(&mut self, placeholder: T) -> &mut Self {
+        self.input_field_placeholder = Some(placeholder.into());
+        self
+    }
+
     /// Use this method if you want to force reply from specific users only.
     /// Targets: 1) users that are @mentioned in the text of
     /// the Message object; 2) if the bot's message is a reply (has reply_to_message_id),
@@ -121,6 +135,10 @@ pub struct KeyboardButton {
     request_contact: bool,
     #[serde(skip_serializing_if = "Not::not")]
     request_location: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_poll: Option<KeyboardButtonPollType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_app: Option<WebAppInfo>,
 }
 
 impl KeyboardButton {
@@ -129,13 +147,15 @@ impl KeyboardButton {
             text: text.as_ref().to_string(),
             request_contact: false,
             request_location: false,
+            request_poll: None,
+            web_app: None,
         }
     }
 
     /// The user's phone number will be sent as a contact when the
     /// button is pressed. Available in private chats only
     pub fn request_contact(&mut self) -> &mut Self {
-        self.request_location = false;
+        self.clear_request();
         self.request_contact = true;
         self
     }
@@ -143,10 +163,43 @@ impl KeyboardButton {
     /// The user's current location will be sent when the
     /// button is pressed. Available in private chats only
     pub fn request_location(&mut self) -> &mut Self {
-        self.request_contact = false;
+        self.clear_request();
         self.request_location = true;
         self
     }
+
+    /// The user will be asked to create a poll and send it to the bot when the button
+    /// is pressed. If `poll_type` is `None`, the user will be allowed to create a poll
+    /// of any type. Available in private chats only
+    pub fn request_poll(&mut self, poll_type: Option<PollType>) -> &mut Self {
+        self.clear_request();
+        self.request_poll = Some(KeyboardButtonPollType { poll_type });
+        self
+    }
+
+    /// The described Web App will be launched when the button is pressed.
+    /// The Web App will be able to send a “web_app_data” service message.
+    /// Available in private chats only.
+    pub fn web_app<W: Into<WebAppInfo>>(&mut self, web_app: W) -> &mut Self {
+        self.clear_request();
+        self.web_app = Some(web_app.into());
+        self
+    }
+
+    fn clear_request(&mut self) {
+        self.request_contact = false;
+        self.request_location = false;
+        self.request_poll = None;
+        self.web_app = None;
+    }
+}
+
+/// This object represents type of a poll, which is allowed to be created
+/// and sent when the corresponding button is pressed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct KeyboardButtonPollType {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    poll_type: Option<PollType>,
 }
 
 impl<'a> From<&'a str> for KeyboardButton {
@@ -192,7 +245,7 @@ impl ReplyKeyboardRemove {
 }
 
 /// This object represents an inline keyboard that appears right next to the message it belongs to.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct InlineKeyboardMarkup {
     inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
 }
@@ -216,6 +269,11 @@ impl InlineKeyboardMarkup {
     pub fn add_empty_row(&mut self) -> &mut Vec<InlineKeyboardButton> {
         self.add_row(Default::default())
     }
+
+    /// Rows of buttons of the keyboard.
+    pub fn inline_keyboard(&self) -> &[Vec<InlineKeyboardButton>] {
+        &self.inline_keyboard
+    }
 }
 
 impl From<Vec<Vec<InlineKeyboardButton>>> for InlineKeyboardMarkup {
@@ -225,7 +283,7 @@ impl From<Vec<Vec<InlineKeyboardButton>>> for InlineKeyboardMarkup {
 }
 
 /// This object represents one button of an inline keyboard.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct InlineKeyboardButton {
     text: String,
     #[serde(flatten)]
@@ -233,7 +291,18 @@ pub struct InlineKeyboardButton {
 }
 
 impl InlineKeyboardButton {
-    /// Data to be sent in a callback query to the bot when button is pressed, 1-64 bytes
+    /// Label text on the button.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Action performed when the button is pressed.
+    pub fn kind(&self) -> &InlineKeyboardButtonKind {
+        &self.kind
+    }
+
+    /// Data to be sent in a callback query to the bot when button is pressed, 1-64 bytes.
+    /// See `callback_data` for a typed and size-checked alternative.
     pub fn callback<T: AsRef<str>, C: AsRef<str>>(text: T, callback: C) -> Self {
         Self {
             text: text.as_ref().to_string(),
@@ -273,24 +342,144 @@ impl InlineKeyboardButton {
             ),
         }
     }
+
+    /// Description of the game that will be launched when the user presses the button.
+    /// This type of button must always be the first button in the first row.
+    pub fn callback_game<T: AsRef<str>>(text: T) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::CallbackGame(CallbackGame {}),
+        }
+    }
+
+    /// Send a Pay button. This type of button must always be the first button in the first row
+    /// and can only be used in invoice messages.
+    pub fn pay<T: AsRef<str>>(text: T) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::Pay(True),
+        }
+    }
+
+    /// An HTTP URL used to automatically authorize the user. Can be used as a replacement
+    /// for the Telegram Login Widget.
+    pub fn login_url<T: AsRef<str>, L: Into<LoginUrl>>(text: T, login_url: L) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::LoginUrl(login_url.into()),
+        }
+    }
+
+    /// Description of the Web App that will be launched when the user presses the button.
+    /// Available only in private chats between a user and the bot.
+    pub fn web_app<T: AsRef<str>, W: Into<WebAppInfo>>(text: T, web_app: W) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::WebApp(web_app.into()),
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum InlineKeyboardButtonKind {
     #[serde(rename = "url")]
     Url(String), // TODO(knsd): Url?
     #[serde(rename = "callback_data")]
-    CallbackData(String), // TODO(knsd) Validate size?
+    CallbackData(String),
     #[serde(rename = "switch_inline_query")]
     SwitchInlineQuery(String),
     #[serde(rename = "switch_inline_query_current_chat")]
     SwitchInlineQueryCurrentChat(String),
-    // #[serde(rename = "callback_game")]
-    //  CallbackGame(CallbackGame),
-    // #[serde(rename = "pay")]
-    //  Pay,
-    // #[serde(rename = "login_url")]
-    //  LoginUrl(LoginUrl),
+    #[serde(rename = "callback_game")]
+    CallbackGame(CallbackGame),
+    #[serde(rename = "pay")]
+    Pay(True),
+    #[serde(rename = "login_url")]
+    LoginUrl(LoginUrl),
+    #[serde(rename = "web_app")]
+    WebApp(WebAppInfo),
+    #[doc(hidden)]
+    #[serde(untagged)]
+    Unknown(Map<String, Value>),
+}
+
+impl InlineKeyboardButtonKind {
+    fn order(&self) -> u8 {
+        match self {
+            InlineKeyboardButtonKind::Url(_) => 0,
+            InlineKeyboardButtonKind::CallbackData(_) => 1,
+            InlineKeyboardButtonKind::SwitchInlineQuery(_) => 2,
+            InlineKeyboardButtonKind::SwitchInlineQueryCurrentChat(_) => 3,
+            InlineKeyboardButtonKind::CallbackGame(_) => 4,
+            InlineKeyboardButtonKind::Pay(_) => 5,
+            InlineKeyboardButtonKind::LoginUrl(_) => 6,
+            InlineKeyboardButtonKind::WebApp(_) => 7,
+            InlineKeyboardButtonKind::Unknown(_) => 8,
+        }
+    }
+}
+
+impl PartialOrd for InlineKeyboardButtonKind {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        use self::InlineKeyboardButtonKind::*;
+
+        match (self, other) {
+            (Url(a), Url(b)) => a.partial_cmp(b),
+            (CallbackData(a), CallbackData(b)) => a.partial_cmp(b),
+            (SwitchInlineQuery(a), SwitchInlineQuery(b)) => a.partial_cmp(b),
+            (SwitchInlineQueryCurrentChat(a), SwitchInlineQueryCurrentChat(b)) => a.partial_cmp(b),
+            (CallbackGame(a), CallbackGame(b)) => a.partial_cmp(b),
+            (Pay(a), Pay(b)) => a.partial_cmp(b),
+            (LoginUrl(a), LoginUrl(b)) => a.partial_cmp(b),
+            (WebApp(a), WebApp(b)) => a.partial_cmp(b),
+            (Unknown(a), Unknown(b)) if a == b => Some(Ordering::Equal),
+            (Unknown(_), Unknown(_)) => None,
+            _ => self.order().partial_cmp(&other.order()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InlineKeyboardButtonKind {
+    fn deserialize<D>(deserializer: D) -> Result<InlineKeyboardButtonKind, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        fn known<T: DeserializeOwned, E: Error>(value: Value) -> Result<T, E> {
+            ::serde_json::from_value(value).map_err(E::custom)
+        }
+
+        const KINDS: &[&str] = &[
+            "url",
+            "callback_data",
+            "switch_inline_query",
+            "switch_inline_query_current_chat",
+            "callback_game",
+            "pay",
+            "login_url",
+            "web_app",
+        ];
+
+        let mut raw: Map<String, Value> = Deserialize::deserialize(deserializer)?;
+
+        let kind = match KINDS.iter().find(|kind| raw.contains_key(**kind)) {
+            Some(kind) => *kind,
+            None => return Ok(InlineKeyboardButtonKind::Unknown(raw)),
+        };
+        let value = raw.remove(kind).unwrap();
+
+        Ok(match kind {
+            "url" => InlineKeyboardButtonKind::Url(known(value)?),
+            "callback_data" => InlineKeyboardButtonKind::CallbackData(known(value)?),
+            "switch_inline_query" => InlineKeyboardButtonKind::SwitchInlineQuery(known(value)?),
+            "switch_inline_query_current_chat" => {
+                InlineKeyboardButtonKind::SwitchInlineQueryCurrentChat(known(value)?)
+            }
+            "callback_game" => InlineKeyboardButtonKind::CallbackGame(known(value)?),
+            "pay" => InlineKeyboardButtonKind::Pay(known(value)?),
+            "login_url" => InlineKeyboardButtonKind::LoginUrl(known(value)?),
+            _ => InlineKeyboardButtonKind::WebApp(known(value)?),
+        })
+    }
 }
 
 /// Upon receiving a message with this object, Telegram clients will
@@ -301,6 +490,8 @@ pub enum InlineKeyboardButtonKind {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 pub struct ForceReply {
     force_reply: True,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_field_placeholder: Option<String>,
     #[serde(skip_serializing_if = "Not::not")]
     selective: bool,
 }
@@ -309,10 +500,18 @@ impl ForceReply {
     pub fn new() -> Self {
         Self {
             force_reply: True,
+            input_field_placeholder: None,
             selective: false,
         }
     }
 
+    /// The placeholder to be shown in the input field when the reply is active;
+    /// 1-64 characters.
+    pub fn input_field_placeholder<T: Into<String>>(&mut self, placeholder: T) -> &mut Self {
+        self.input_field_placeholder = Some(placeholder.into());
+        self
+    }
+
     /// Use this method if you want to force reply from specific users only.
     /// Targets: 1) users that are @mentioned in the text of
     /// the Message object; 2) if the bot's message is a reply (has reply_to_message_id),
@@ -4,7 +4,7 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
 pub struct ShippingQuery {
     /// Unique query identifier
-    pub id: CallbackQueryId,
+    pub id: ShippingQueryId,
     /// User who sent the query
     pub from: User,
     /// Bot specified invoice payload
@@ -52,6 +52,9 @@ pub enum UpdateKind {
     /// A chat member's status was updated in a chat. The bot must be an administrator in the chat and must explicitly specify “chat_member” in the list of allowed_updates to receive these updates.
     #[serde(rename = "chat_member")]
     ChatMember(ChatMemberUpdate),
+    /// A request to join the chat has been sent. The bot must have the can_invite_users administrator right in the chat to receive these updates.
+    #[serde(rename = "chat_join_request")]
+    ChatJoinRequest(ChatJoinRequest),
     #[doc(hidden)]
     Error(String),
     #[doc(hidden)]
@@ -0,0 +1,74 @@
+/// Describes a Web App.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct WebAppInfo {
+    /// An HTTPS URL of a Web App to be opened with additional data.
+    pub url: String,
+}
+
+impl WebAppInfo {
+    pub fn new<U: Into<String>>(url: U) -> Self {
+        WebAppInfo { url: url.into() }
+    }
+}
+
+impl<'a> From<&'a str> for WebAppInfo {
+    fn from(value: &'a str) -> Self {
+        WebAppInfo::new(value)
+    }
+}
+
+impl From<String> for WebAppInfo {
+    fn from(value: String) -> Self {
+        WebAppInfo::new(value)
+    }
+}
+
+/// Describes data sent from a Web App to the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct WebAppData {
+    /// The data. Be aware that a bad client can send arbitrary data in this field.
+    pub data: String,
+    /// Text of the web_app keyboard button from which the Web App was opened.
+    pub button_text: String,
+}
+
+/// Describes an inline message sent by a Web App on behalf of a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct SentWebAppMessage {
+    /// Identifier of the sent inline message. Available only if there is an inline keyboard
+    /// attached to the message.
+    pub inline_message_id: Option<String>,
+}
+
+/// This object describes the bot's menu button in a private chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MenuButton {
+    /// Opens the bot's list of commands.
+    #[serde(rename = "commands")]
+    Commands,
+    /// Launches a Web App.
+    #[serde(rename = "web_app")]
+    WebApp {
+        /// Text on the button.
+        text: String,
+        /// Description of the Web App that will be launched when the user presses the button.
+        web_app: WebAppInfo,
+    },
+    /// No specific value for the menu button was set.
+    #[serde(rename = "default")]
+    Default,
+}
+
+impl MenuButton {
+    pub fn web_app<T, W>(text: T, web_app: W) -> Self
+    where
+        T: Into<String>,
+        W: Into<WebAppInfo>,
+    {
+        MenuButton::WebApp {
+            text: text.into(),
+            web_app: web_app.into(),
+        }
+    }
+}
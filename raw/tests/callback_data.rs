@@ -0,0 +1,82 @@
+mod common;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use telegram_bot_raw::types::callback_data::{
+    decode_callback_data, encode_callback_data, CallbackDataError, CallbackDataKey,
+};
+use telegram_bot_raw::types::reply_markup::{InlineKeyboardButton, InlineKeyboardButtonKind};
+
+use common::{callback_query, key};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Action {
+    Vote { poll: u32, option: u8 },
+    Page(u16),
+}
+
+#[test]
+fn round_trip() {
+    let action = Action::Vote { poll: 7, option: 2 };
+    let button = InlineKeyboardButton::callback_data("Yes", &key(), &action).unwrap();
+    let data = match button.kind() {
+        InlineKeyboardButtonKind::CallbackData(data) => data.clone(),
+        kind => panic!("unexpected button kind: {:?}", kind),
+    };
+    // Variant, poll and option take a byte each, followed by the 8 bytes of the signature.
+    assert_eq!(URL_SAFE_NO_PAD.decode(&data).unwrap()[..3], [0, 7, 2]);
+    assert_eq!(data.len(), 15);
+    assert_eq!(
+        callback_query(&data).decode_data::<Action>(&key()).unwrap(),
+        action
+    );
+}
+
+#[test]
+fn too_long_data_is_rejected() {
+    let long = "x".repeat(40);
+    match InlineKeyboardButton::callback_data("Text", &key(), &long) {
+        Err(CallbackDataError::TooLong(66)) => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert!(encode_callback_data(&key(), &"x".repeat(39)).is_ok());
+
+    assert!(matches!(
+        decode_callback_data::<Action>(&key(), &"1".repeat(65)),
+        Err(CallbackDataError::TooLong(65))
+    ));
+}
+
+#[test]
+fn tampered_data_is_rejected() {
+    let data = encode_callback_data(&key(), &Action::Vote { poll: 7, option: 2 }).unwrap();
+    let mut bytes = URL_SAFE_NO_PAD.decode(&data).unwrap();
+    bytes[1] = 8;
+    let tampered = URL_SAFE_NO_PAD.encode(bytes);
+
+    let error = callback_query(&tampered)
+        .decode_data::<Action>(&key())
+        .unwrap_err();
+    assert!(matches!(error, CallbackDataError::Tampered));
+    assert_eq!(error.to_string(), "callback data has an invalid signature");
+
+    let other_key = CallbackDataKey::new("654321:other-token");
+    assert!(matches!(
+        decode_callback_data::<Action>(&other_key, &data),
+        Err(CallbackDataError::Tampered)
+    ));
+    assert!(matches!(
+        decode_callback_data::<Action>(&key(), r#"{"Page":"1; DROP TABLE"}"#),
+        Err(CallbackDataError::Tampered)
+    ));
+}
+
+#[test]
+fn mismatched_type_is_malformed() {
+    let data = encode_callback_data(&key(), &(500u16, "text")).unwrap();
+    let error = decode_callback_data::<Action>(&key(), &data).unwrap_err();
+    assert!(matches!(error, CallbackDataError::Malformed(_)));
+    assert!(error.to_string().starts_with("malformed callback data"));
+}
@@ -0,0 +1,32 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use serde_json::{json, Value};
+
+use telegram_bot_raw::types::callback_data::CallbackDataKey;
+use telegram_bot_raw::types::callback_query::CallbackQuery;
+use telegram_bot_raw::types::chat::User;
+
+/// Key the test callback data is signed with.
+pub fn key() -> CallbackDataKey {
+    CallbackDataKey::new("123456:secret-token")
+}
+
+pub fn user_value() -> Value {
+    json!({"id": 42, "is_bot": false, "first_name": "Fedor"})
+}
+
+pub fn user() -> User {
+    serde_json::from_value(user_value()).unwrap()
+}
+
+/// Callback query of the user 42 with `data`.
+pub fn callback_query(data: &str) -> CallbackQuery {
+    serde_json::from_value(json!({
+        "id": "1",
+        "from": user_value(),
+        "chat_instance": "-1",
+        "data": data,
+    }))
+    .unwrap()
+}
@@ -0,0 +1,93 @@
+use telegram_bot_raw::requests::{Body, Request, SendMessage};
+use telegram_bot_raw::types::chat::User;
+use telegram_bot_raw::types::message::{MessageEntity, MessageEntityKind::*};
+use telegram_bot_raw::types::refs::{ChatId, UserId};
+use telegram_bot_raw::FormattedText;
+
+fn user() -> User {
+    User {
+        id: UserId::new(42),
+        first_name: "Fedor".into(),
+        last_name: None,
+        username: None,
+        is_bot: false,
+        language_code: None,
+    }
+}
+
+#[test]
+fn user_input_is_escaped() {
+    let mut text = FormattedText::new();
+    text.text("Hello, ")
+        .bold("snake_case_name")
+        .text("! 2 * 2 < 5.");
+
+    assert_eq!(text.as_str(), "Hello, snake_case_name! 2 * 2 < 5.");
+    assert_eq!(
+        text.to_html(),
+        "Hello, <b>snake_case_name</b>! 2 * 2 &lt; 5."
+    );
+    assert_eq!(
+        text.to_markdown_v2(),
+        "Hello, *snake\\_case\\_name*\\! 2 \\* 2 < 5\\."
+    );
+}
+
+#[test]
+fn entities_use_utf16_offsets() {
+    let mut text = FormattedText::new();
+    text.text("🎲 ")
+        .italic("Привет")
+        .text(" ")
+        .text_mention("Fedor", &user())
+        .spoiler("!");
+
+    assert_eq!(
+        text.entities(),
+        &[
+            MessageEntity::new(Italic, 3, 6),
+            MessageEntity::new(TextMention(user()), 10, 5),
+            MessageEntity::new(Spoiler, 15, 1),
+        ][..]
+    );
+}
+
+#[test]
+fn nested_formatting() {
+    let mut inner = FormattedText::new();
+    inner.text("see ").link("docs", "https://example.com");
+
+    let mut text = FormattedText::new();
+    text.bold(&mut inner).text(" ").code("a_b").text(" ");
+    text.pre_with_language("let x = 1;", "rust");
+
+    assert_eq!(
+        text.to_html(),
+        "<b>see <a href=\"https://example.com\">docs</a></b> <code>a_b</code> \
+         <pre><code class=\"language-rust\">let x = 1;</code></pre>"
+    );
+    assert_eq!(
+        text.to_markdown_v2(),
+        "*see [docs](https://example.com)* `a_b` ```rust\nlet x = 1;\n```"
+    );
+}
+
+#[test]
+fn send_message_with_formatted_text() {
+    let mut text = FormattedText::new();
+    text.text("a ").bold("b");
+
+    let mut request = SendMessage::new(ChatId::new(1), "");
+    request.formatted_text(text);
+
+    let body = match request.serialize().unwrap().body {
+        Body::Json(body) => body,
+        _ => panic!("unexpected body"),
+    };
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["text"], "a b");
+    assert_eq!(
+        json["entities"],
+        serde_json::json!([{"type": "bold", "offset": 2, "length": 1}])
+    );
+}
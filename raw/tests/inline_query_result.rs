@@ -0,0 +1,265 @@
+use serde_json::json;
+
+use telegram_bot_raw::types::inline_query_result::*;
+use telegram_bot_raw::types::reply_markup::{InlineKeyboardButton, InlineKeyboardMarkup};
+use telegram_bot_raw::ParseMode;
+
+fn to_json(result: impl Into<InlineQueryResult>) -> serde_json::Value {
+    serde_json::to_value(result.into()).unwrap()
+}
+
+#[test]
+fn photo() {
+    let mut photo = InlineQueryResultPhoto::new(
+        "1",
+        "https://example.com/a.jpg",
+        "https://example.com/t.jpg",
+    );
+    photo
+        .caption("<b>Cat</b>")
+        .parse_mode(ParseMode::Html)
+        .photo_width(640)
+        .reply_markup(InlineKeyboardMarkup::from(vec![vec![
+            InlineKeyboardButton::url("Open", "https://example.com"),
+        ]]));
+
+    assert_eq!(
+        to_json(photo),
+        json!({
+            "type": "photo",
+            "id": "1",
+            "photo_url": "https://example.com/a.jpg",
+            "thumb_url": "https://example.com/t.jpg",
+            "photo_width": 640,
+            "caption": "<b>Cat</b>",
+            "parse_mode": "HTML",
+            "reply_markup": {"inline_keyboard": [[{"text": "Open", "url": "https://example.com"}]]},
+        })
+    );
+}
+
+#[test]
+fn cached_document_with_content() {
+    let mut document = InlineQueryResultCachedDocument::new("2", "Report", "file-id");
+    document.input_message_content(InputTextMessageContent {
+        message_text: "See the report".into(),
+        parse_mode: None,
+        disable_web_page_preview: false,
+    });
+
+    assert_eq!(
+        to_json(document),
+        json!({
+            "type": "document",
+            "id": "2",
+            "title": "Report",
+            "document_file_id": "file-id",
+            "input_message_content": {"message_text": "See the report"},
+        })
+    );
+}
+
+#[test]
+fn venue_and_contact() {
+    let mut venue = InlineQueryResultVenue::new("3", 52.5, 13.25, "Office", "Main st. 1");
+    venue.foursquare_id("4sq");
+    assert_eq!(
+        to_json(venue),
+        json!({
+            "type": "venue",
+            "id": "3",
+            "latitude": 52.5,
+            "longitude": 13.25,
+            "title": "Office",
+            "address": "Main st. 1",
+            "foursquare_id": "4sq",
+        })
+    );
+
+    let contact = InlineQueryResultContact::new("4", "+10000000000", "Fedor");
+    assert_eq!(
+        to_json(contact),
+        json!({
+            "type": "contact",
+            "id": "4",
+            "phone_number": "+10000000000",
+            "first_name": "Fedor",
+        })
+    );
+}
+
+#[test]
+fn article() {
+    let content = InputTextMessageContent {
+        message_text: "Hello".into(),
+        parse_mode: None,
+        disable_web_page_preview: false,
+    };
+    let mut article = InlineQueryResultArticle::new("5", "Greeting", content);
+    article.description("Says hello");
+    assert_eq!(
+        to_json(article),
+        json!({
+            "type": "article",
+            "id": "5",
+            "title": "Greeting",
+            "input_message_content": {"message_text": "Hello"},
+            "description": "Says hello",
+        })
+    );
+}
+
+#[test]
+fn animations() {
+    let gif = InlineQueryResultGif::new(
+        "6",
+        "https://example.com/a.gif",
+        "https://example.com/t.jpg",
+    );
+    assert_eq!(
+        to_json(gif),
+        json!({
+            "type": "gif",
+            "id": "6",
+            "gif_url": "https://example.com/a.gif",
+            "thumb_url": "https://example.com/t.jpg",
+        })
+    );
+
+    let mpeg4 = InlineQueryResultMpeg4Gif::new(
+        "7",
+        "https://example.com/a.mp4",
+        "https://example.com/t.jpg",
+    );
+    assert_eq!(
+        to_json(mpeg4),
+        json!({
+            "type": "mpeg4_gif",
+            "id": "7",
+            "mpeg4_url": "https://example.com/a.mp4",
+            "thumb_url": "https://example.com/t.jpg",
+        })
+    );
+}
+
+#[test]
+fn video() {
+    let video = InlineQueryResultVideo::new(
+        "8",
+        "https://example.com/a.mp4",
+        "video/mp4",
+        "https://example.com/t.jpg",
+        "Clip",
+    );
+    assert_eq!(
+        to_json(video),
+        json!({
+            "type": "video",
+            "id": "8",
+            "video_url": "https://example.com/a.mp4",
+            "mime_type": "video/mp4",
+            "thumb_url": "https://example.com/t.jpg",
+            "title": "Clip",
+        })
+    );
+}
+
+#[test]
+fn audio_and_voice() {
+    let mut audio = InlineQueryResultAudio::new("9", "https://example.com/a.mp3", "Song");
+    audio.performer("Band").audio_duration(180);
+    assert_eq!(
+        to_json(audio),
+        json!({
+            "type": "audio",
+            "id": "9",
+            "audio_url": "https://example.com/a.mp3",
+            "title": "Song",
+            "performer": "Band",
+            "audio_duration": 180,
+        })
+    );
+
+    let mut voice = InlineQueryResultVoice::new("10", "https://example.com/a.ogg", "Note");
+    voice.voice_duration(15);
+    assert_eq!(
+        to_json(voice),
+        json!({
+            "type": "voice",
+            "id": "10",
+            "voice_url": "https://example.com/a.ogg",
+            "title": "Note",
+            "voice_duration": 15,
+        })
+    );
+}
+
+#[test]
+fn document_location_and_game() {
+    let document = InlineQueryResultDocument::new(
+        "11",
+        "Manual",
+        "https://example.com/a.pdf",
+        "application/pdf",
+    );
+    assert_eq!(
+        to_json(document),
+        json!({
+            "type": "document",
+            "id": "11",
+            "title": "Manual",
+            "document_url": "https://example.com/a.pdf",
+            "mime_type": "application/pdf",
+        })
+    );
+
+    let location = InlineQueryResultLocation::new("12", 52.5, 13.25, "Here");
+    assert_eq!(
+        to_json(location),
+        json!({
+            "type": "location",
+            "id": "12",
+            "latitude": 52.5,
+            "longitude": 13.25,
+            "title": "Here",
+        })
+    );
+
+    let game = InlineQueryResultGame::new("13", "tetris");
+    assert_eq!(
+        to_json(game),
+        json!({"type": "game", "id": "13", "game_short_name": "tetris"})
+    );
+}
+
+#[test]
+fn cached_media() {
+    assert_eq!(
+        to_json(InlineQueryResultCachedPhoto::new("14", "photo-id")),
+        json!({"type": "photo", "id": "14", "photo_file_id": "photo-id"})
+    );
+    assert_eq!(
+        to_json(InlineQueryResultCachedGif::new("15", "gif-id")),
+        json!({"type": "gif", "id": "15", "gif_file_id": "gif-id"})
+    );
+    assert_eq!(
+        to_json(InlineQueryResultCachedMpeg4Gif::new("16", "mpeg4-id")),
+        json!({"type": "mpeg4_gif", "id": "16", "mpeg4_file_id": "mpeg4-id"})
+    );
+    assert_eq!(
+        to_json(InlineQueryResultCachedSticker::new("17", "sticker-id")),
+        json!({"type": "sticker", "id": "17", "sticker_file_id": "sticker-id"})
+    );
+    assert_eq!(
+        to_json(InlineQueryResultCachedVideo::new("18", "video-id", "Clip")),
+        json!({"type": "video", "id": "18", "video_file_id": "video-id", "title": "Clip"})
+    );
+    assert_eq!(
+        to_json(InlineQueryResultCachedVoice::new("19", "voice-id", "Note")),
+        json!({"type": "voice", "id": "19", "voice_file_id": "voice-id", "title": "Note"})
+    );
+    assert_eq!(
+        to_json(InlineQueryResultCachedAudio::new("20", "audio-id")),
+        json!({"type": "audio", "id": "20", "audio_file_id": "audio-id"})
+    );
+}
@@ -0,0 +1,113 @@
+use telegram_bot_raw::types::message::{MessageEntity, MessageEntityKind, MessageKind};
+use telegram_bot_raw::types::update::{Update, UpdateKind};
+
+#[test]
+fn extract_ascii() {
+    let text = "Hello, world";
+    let entity = MessageEntity::new(MessageEntityKind::Bold, 7, 5);
+    assert_eq!(entity.extract(text), Some("world"));
+}
+
+#[test]
+fn extract_after_emoji() {
+    // "👋" is a single char, but takes two UTF-16 code units and four bytes.
+    let text = "👋 Привет, @username!";
+    let entity = MessageEntity::new(MessageEntityKind::Mention, 11, 9);
+    assert_eq!(entity.extract(text), Some("@username"));
+    assert_eq!(entity.byte_range(text), Some(19..28));
+}
+
+#[test]
+fn extract_emoji() {
+    let text = "a👍b";
+    let entity = MessageEntity::new(MessageEntityKind::Bold, 1, 2);
+    assert_eq!(entity.extract(text), Some("👍"));
+}
+
+#[test]
+fn extract_splitting_surrogate_pair() {
+    let text = "a👍b";
+    let entity = MessageEntity::new(MessageEntityKind::Bold, 1, 1);
+    assert_eq!(entity.extract(text), None);
+}
+
+#[test]
+fn extract_out_of_bounds() {
+    let text = "short";
+    let entity = MessageEntity::new(MessageEntityKind::Bold, 3, 10);
+    assert_eq!(entity.extract(text), None);
+}
+
+#[test]
+fn extract_whole_text() {
+    let entity = MessageEntity::new(MessageEntityKind::Bold, 0, 4);
+    assert_eq!(entity.extract("ёжик"), Some("ёжик"));
+    assert_eq!(entity.extract("🎲🎯"), Some("🎲🎯"));
+    assert_eq!(entity.byte_range("🎲🎯"), Some(0..8));
+}
+
+#[test]
+fn parse_modern_entities() {
+    let data = r#"{
+        "update_id": 424151310,
+        "message": {
+            "message_id": 1200,
+            "from": {"id": 174976101, "first_name": "Fedor", "is_bot": false},
+            "chat": {"id": 174976101, "first_name": "Fedor", "type": "private"},
+            "date": 1625002000,
+            "text": "🔥 u s ||x|| $USD +1234567 ☺ fn main() {}",
+            "entities": [
+                {"type": "underline", "offset": 3, "length": 1},
+                {"type": "strikethrough", "offset": 5, "length": 1},
+                {"type": "spoiler", "offset": 7, "length": 5},
+                {"type": "cashtag", "offset": 13, "length": 4},
+                {"type": "phone_number", "offset": 18, "length": 8},
+                {"type": "custom_emoji", "offset": 27, "length": 1, "custom_emoji_id": "5368324170671202286"},
+                {"type": "pre", "offset": 29, "length": 12, "language": "rust"}
+            ]
+        }
+    }"#;
+
+    let update: Update = serde_json::from_str(data).unwrap();
+    let message = match update.kind {
+        UpdateKind::Message(message) => message,
+        _ => panic!("unexpected update kind"),
+    };
+    let (text, entities) = match message.kind {
+        MessageKind::Text { data, entities } => (data, entities),
+        _ => panic!("unexpected message kind"),
+    };
+
+    let extracted: Vec<_> = entities
+        .iter()
+        .map(|entity| (entity.kind.clone(), entity.extract(&text).unwrap()))
+        .collect();
+
+    assert_eq!(
+        extracted,
+        vec![
+            (MessageEntityKind::Underline, "u"),
+            (MessageEntityKind::Strikethrough, "s"),
+            (MessageEntityKind::Spoiler, "||x||"),
+            (MessageEntityKind::Cashtag, "$USD"),
+            (MessageEntityKind::PhoneNumber, "+1234567"),
+            (
+                MessageEntityKind::CustomEmoji("5368324170671202286".into()),
+                "☺"
+            ),
+            (MessageEntityKind::Pre(Some("rust".into())), "fn main() {}"),
+        ]
+    );
+}
+
+#[test]
+fn serialize_roundtrip() {
+    let entities = vec![
+        MessageEntity::new(MessageEntityKind::Pre(Some("rust".into())), 0, 4),
+        MessageEntity::new(MessageEntityKind::CustomEmoji("42".into()), 5, 2),
+        MessageEntity::new(MessageEntityKind::TextLink("https://t.me".into()), 8, 3),
+    ];
+    let json = serde_json::to_string(&entities).unwrap();
+    let parsed: Vec<MessageEntity> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, entities);
+}
@@ -0,0 +1,175 @@
+use telegram_bot_raw::formatting::{
+    parse_html, parse_markdown, parse_markdown_v2, parse_with_parse_mode, render_html,
+    render_markdown_v2, MarkupError,
+};
+use telegram_bot_raw::types::message::ParseMode;
+use telegram_bot_raw::types::message::{MessageEntity, MessageEntityKind::*};
+
+fn entities() -> (&'static str, Vec<MessageEntity>) {
+    let text = "bold and italic, code 1 < 2 & 3 > 2. Done! 😀 link";
+    let entities = vec![
+        MessageEntity::new(Bold, 0, 15),
+        MessageEntity::new(Underline, 9, 6),
+        MessageEntity::new(Italic, 9, 6),
+        MessageEntity::new(Code, 17, 4),
+        MessageEntity::new(Spoiler, 43, 2),
+        MessageEntity::new(TextLink("https://example.com/(a)".into()), 46, 4),
+    ];
+    (text, entities)
+}
+
+/// Entities with equal ranges may come in any order.
+fn assert_same_entities(parsed: &[MessageEntity], expected: &[MessageEntity]) {
+    assert_eq!(parsed.len(), expected.len());
+    for entity in expected {
+        assert!(parsed.contains(entity), "{:?} is missing", entity);
+    }
+}
+
+#[test]
+fn html_round_trip() {
+    let (text, entities) = entities();
+    let parsed = parse_html(&render_html(text, &entities)).unwrap();
+    assert_eq!(parsed.as_str(), text);
+    assert_same_entities(parsed.entities(), &entities);
+}
+
+#[test]
+fn markdown_v2_round_trip() {
+    let (text, entities) = entities();
+    let parsed = parse_markdown_v2(&render_markdown_v2(text, &entities)).unwrap();
+    assert_eq!(parsed.as_str(), text);
+    assert_same_entities(parsed.entities(), &entities);
+}
+
+#[test]
+fn html_character_references() {
+    let parsed = parse_html("&lt;b&gt; &quot;a&quot; &#39;b&#x27; &amp; &unknown; & c").unwrap();
+    assert_eq!(parsed.as_str(), "<b> \"a\" 'b' & &unknown; & c");
+    assert!(parsed.entities().is_empty());
+}
+
+#[test]
+fn html_offsets_in_utf16() {
+    let parsed = parse_html("😀 <b>ёж</b>").unwrap();
+    assert_eq!(parsed.entities(), &[MessageEntity::new(Bold, 3, 2)]);
+}
+
+#[test]
+fn html_attributes() {
+    let parsed = parse_html(
+        "<span class='tg-spoiler'>a</span> <a href=\"https://t.me/?a=1&amp;b=2\">b</a> \
+         <tg-emoji emoji-id=5368324170671202286>c</tg-emoji>",
+    )
+    .unwrap();
+    assert_eq!(parsed.as_str(), "a b c");
+    assert_eq!(
+        parsed.entities(),
+        &[
+            MessageEntity::new(Spoiler, 0, 1),
+            MessageEntity::new(TextLink("https://t.me/?a=1&b=2".into()), 2, 1),
+            MessageEntity::new(CustomEmoji("5368324170671202286".into()), 4, 1),
+        ]
+    );
+}
+
+#[test]
+fn markdown_v2_escaping() {
+    let parsed = parse_markdown_v2("1\\. \\*not bold\\* `a\\`b` [x](https://t.me/\\))").unwrap();
+    assert_eq!(parsed.as_str(), "1. *not bold* a`b x");
+    assert_eq!(
+        parsed.entities(),
+        &[
+            MessageEntity::new(Code, 14, 3),
+            MessageEntity::new(TextLink("https://t.me/)".into()), 18, 1),
+        ]
+    );
+}
+
+#[test]
+fn markdown_v2_nested_and_adjacent() {
+    let parsed =
+        parse_markdown_v2("*bold ___italic underline_\r__* ![👍](tg://emoji?id=5)").unwrap();
+    assert_eq!(parsed.as_str(), "bold italic underline 👍");
+    assert_same_entities(
+        parsed.entities(),
+        &[
+            MessageEntity::new(Bold, 0, 21),
+            MessageEntity::new(Underline, 5, 16),
+            MessageEntity::new(Italic, 5, 16),
+            MessageEntity::new(CustomEmoji("5".into()), 22, 2),
+        ],
+    );
+}
+
+#[test]
+fn with_parse_mode() {
+    for (parse_mode, markup) in [
+        (ParseMode::Html, "<b>bold</b>"),
+        (ParseMode::MarkdownV2, "*bold*"),
+        (ParseMode::Markdown, "*bold*"),
+    ] {
+        let parsed = parse_with_parse_mode(markup, parse_mode).unwrap();
+        assert_eq!(parsed.as_str(), "bold");
+        assert_eq!(parsed.entities(), &[MessageEntity::new(Bold, 0, 4)]);
+    }
+}
+
+#[test]
+fn pre_with_language() {
+    let parsed = parse_html("<pre><code class=\"language-rust\">let a = 1;</code></pre>").unwrap();
+    assert_eq!(parsed.as_str(), "let a = 1;");
+    assert_eq!(
+        parsed.entities(),
+        &[MessageEntity::new(Pre(Some("rust".into())), 0, 10)]
+    );
+
+    let parsed = parse_markdown_v2("```rust\nlet a = 1;```").unwrap();
+    assert_eq!(parsed.as_str(), "let a = 1;");
+    assert_eq!(
+        parsed.entities(),
+        &[MessageEntity::new(Pre(Some("rust".into())), 0, 10)]
+    );
+}
+
+#[test]
+fn legacy_markdown() {
+    let parsed = parse_markdown("*bold* _italic_ [link](https://example.com)").unwrap();
+    assert_eq!(parsed.as_str(), "bold italic link");
+    assert_eq!(
+        parsed.entities(),
+        &[
+            MessageEntity::new(Bold, 0, 4),
+            MessageEntity::new(Italic, 5, 6),
+            MessageEntity::new(TextLink("https://example.com".into()), 12, 4),
+        ]
+    );
+}
+
+#[test]
+fn invalid_markup() {
+    assert!(matches!(
+        parse_html("<b>bold"),
+        Err(MarkupError::Unclosed(_))
+    ));
+    assert!(matches!(
+        parse_html("<b>bold</i>"),
+        Err(MarkupError::UnmatchedTag(_))
+    ));
+    assert!(matches!(
+        parse_html("<blink>text</blink>"),
+        Err(MarkupError::UnsupportedTag(_))
+    ));
+    assert!(matches!(
+        parse_markdown_v2("Done!"),
+        Err(MarkupError::UnescapedCharacter('!'))
+    ));
+    assert_eq!(
+        parse_markdown_v2("_italic"),
+        Err(MarkupError::Unclosed("_".into()))
+    );
+    assert_eq!(
+        parse_markdown("`code"),
+        Err(MarkupError::Unclosed("`".into()))
+    );
+}
@@ -0,0 +1,122 @@
+use telegram_bot_raw::formatting::{render_html, render_markdown_v2};
+use telegram_bot_raw::types::chat::User;
+use telegram_bot_raw::types::message::{MessageEntity, MessageEntityKind::*};
+use telegram_bot_raw::types::refs::UserId;
+
+#[test]
+fn plain_text_is_escaped() {
+    let text = "1 < 2 & 3 > 2. Done!";
+    assert_eq!(render_html(text, &[]), "1 &lt; 2 &amp; 3 &gt; 2. Done!");
+    assert_eq!(render_markdown_v2(text, &[]), "1 < 2 & 3 \\> 2\\. Done\\!");
+}
+
+#[test]
+fn simple_entities() {
+    let text = "bold italic code";
+    let entities = vec![
+        MessageEntity::new(Bold, 0, 4),
+        MessageEntity::new(Italic, 5, 6),
+        MessageEntity::new(Code, 12, 4),
+    ];
+    assert_eq!(
+        render_html(text, &entities),
+        "<b>bold</b> <i>italic</i> <code>code</code>"
+    );
+    assert_eq!(
+        render_markdown_v2(text, &entities),
+        "*bold* _italic_ `code`"
+    );
+}
+
+#[test]
+fn nested_entities() {
+    let text = "bold and italic";
+    let entities = vec![
+        MessageEntity::new(Bold, 0, 15),
+        MessageEntity::new(Italic, 9, 6),
+    ];
+    assert_eq!(
+        render_html(text, &entities),
+        "<b>bold and <i>italic</i></b>"
+    );
+    assert_eq!(render_markdown_v2(text, &entities), "*bold and _italic_*");
+}
+
+#[test]
+fn overlapping_entities_are_split() {
+    let text = "abcdef";
+    let entities = vec![
+        MessageEntity::new(Bold, 0, 4),
+        MessageEntity::new(Italic, 2, 4),
+    ];
+    assert_eq!(render_html(text, &entities), "<b>ab<i>cd</i></b><i>ef</i>");
+}
+
+#[test]
+fn italic_underline_are_separated() {
+    let text = "text";
+    let entities = vec![
+        MessageEntity::new(Underline, 0, 4),
+        MessageEntity::new(Italic, 0, 4),
+    ];
+    assert_eq!(render_markdown_v2(text, &entities), "__\r_text_\r__");
+}
+
+#[test]
+fn links_and_mentions() {
+    let text = "docs (v2) and Fedor";
+    let user = User {
+        id: UserId::new(42),
+        first_name: "Fedor".into(),
+        last_name: None,
+        username: None,
+        is_bot: false,
+        language_code: None,
+    };
+    let entities = vec![
+        MessageEntity::new(TextLink("https://example.com/a_(b)".into()), 0, 9),
+        MessageEntity::new(TextMention(user), 14, 5),
+    ];
+    assert_eq!(
+        render_html(text, &entities),
+        "<a href=\"https://example.com/a_(b)\">docs (v2)</a> and <a href=\"tg://user?id=42\">Fedor</a>"
+    );
+    assert_eq!(
+        render_markdown_v2(text, &entities),
+        "[docs \\(v2\\)](https://example.com/a_(b\\)) and [Fedor](tg://user?id=42)"
+    );
+}
+
+#[test]
+fn pre_with_language() {
+    let text = "fn main() { let a = `b`; }";
+    let entities = vec![
+        MessageEntity::new(Pre(Some("rust".into())), 0, 26),
+        MessageEntity::new(Bold, 0, 2),
+    ];
+    assert_eq!(
+        render_html(text, &entities),
+        "<pre><code class=\"language-rust\">fn main() { let a = `b`; }</code></pre>"
+    );
+    assert_eq!(
+        render_markdown_v2(text, &entities),
+        "```rust\nfn main() { let a = \\`b\\`; }\n```"
+    );
+}
+
+#[test]
+fn multibyte_offsets() {
+    let text = "👋 Привет, мир!";
+    let entities = vec![
+        MessageEntity::new(Spoiler, 3, 6),
+        MessageEntity::new(Strikethrough, 11, 3),
+    ];
+    assert_eq!(
+        render_html(text, &entities),
+        "👋 <tg-spoiler>Привет</tg-spoiler>, <s>мир</s>!"
+    );
+    assert_eq!(
+        render_markdown_v2(text, &entities),
+        "👋 ||Привет||, ~мир~\\!"
+    );
+}
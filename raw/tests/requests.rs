@@ -0,0 +1,385 @@
+mod common;
+
+use serde_json::{json, Value};
+
+use telegram_bot_raw::requests::*;
+use telegram_bot_raw::types::*;
+
+use common::{user, user_value};
+
+fn body<R: Request>(request: R) -> Value {
+    match request.serialize().unwrap().body {
+        Body::Json(body) => serde_json::from_str(&body).unwrap(),
+        _ => panic!("unexpected body"),
+    }
+}
+
+fn response<R: Request>(result: Value) -> <R::Response as ResponseType>::Type {
+    let body = serde_json::to_vec(&json!({"ok": true, "result": result})).unwrap();
+    R::Response::deserialize(HttpResponse { body: Some(body) }).unwrap()
+}
+
+fn channel() -> Chat {
+    serde_json::from_value(json!({"id": -1001, "type": "channel", "title": "News"})).unwrap()
+}
+
+#[test]
+fn restrict_chat_member() {
+    let mut permissions = ChatPermissions::new();
+    permissions.can_send_messages(true).can_send_polls(false);
+
+    let mut request = ChatId::new(-100).restrict(user(), permissions);
+    request.until_date(1700000000);
+
+    assert_eq!(
+        body(request),
+        json!({
+            "chat_id": -100,
+            "user_id": 42,
+            "permissions": {"can_send_messages": true, "can_send_polls": false},
+            "until_date": 1700000000,
+        })
+    );
+}
+
+#[test]
+fn promote_chat_member() {
+    let mut request = user().promote_in(ChatId::new(-100));
+    request.can_pin_messages().can_invite_users();
+
+    assert_eq!(
+        body(request),
+        json!({
+            "chat_id": -100,
+            "user_id": 42,
+            "can_invite_users": true,
+            "can_pin_messages": true,
+        })
+    );
+}
+
+#[test]
+fn set_chat_permissions() {
+    let mut permissions = ChatPermissions::new();
+    permissions.can_change_info(false);
+
+    assert_eq!(
+        body(ChatId::new(-100).set_permissions(permissions)),
+        json!({"chat_id": -100, "permissions": {"can_change_info": false}})
+    );
+}
+
+#[test]
+fn ban_chat_sender_chat() {
+    let sender_chat = channel();
+    let expected = json!({"chat_id": -100, "sender_chat_id": -1001});
+
+    assert_eq!(
+        body(sender_chat.ban_sender_chat_in(ChatId::new(-100))),
+        expected
+    );
+    assert_eq!(
+        body(ChatId::new(-100).ban_sender_chat(&sender_chat)),
+        expected
+    );
+}
+
+#[test]
+fn set_chat_administrator_custom_title() {
+    let expected = json!({"chat_id": -100, "user_id": 42, "custom_title": "Moderator"});
+    assert_eq!(
+        body(ChatId::new(-100).set_custom_title(user(), "Moderator")),
+        expected
+    );
+    assert_eq!(
+        body(user().set_custom_title_in(ChatId::new(-100), "Moderator")),
+        expected
+    );
+}
+
+#[test]
+fn set_my_commands_with_scopes() {
+    let commands = vec![
+        BotCommand::new("start", "Start the bot"),
+        BotCommand::new("help", "Show help"),
+    ];
+    let scopes = vec![
+        (BotCommandScope::Default, json!({"type": "default"})),
+        (
+            BotCommandScope::AllPrivateChats,
+            json!({"type": "all_private_chats"}),
+        ),
+        (
+            BotCommandScope::AllGroupChats,
+            json!({"type": "all_group_chats"}),
+        ),
+        (
+            BotCommandScope::AllChatAdministrators,
+            json!({"type": "all_chat_administrators"}),
+        ),
+        (
+            BotCommandScope::chat(ChatId::new(-100)),
+            json!({"type": "chat", "chat_id": -100}),
+        ),
+        (
+            BotCommandScope::chat_administrators(ChatRef::ChannelUsername("@news".into())),
+            json!({"type": "chat_administrators", "chat_id": "@news"}),
+        ),
+        (
+            BotCommandScope::chat_member(ChatId::new(-100), user()),
+            json!({"type": "chat_member", "chat_id": -100, "user_id": 42}),
+        ),
+    ];
+
+    for (scope, expected) in scopes {
+        let mut request = SetMyCommands::new(commands.clone());
+        request.scope(scope).language_code("en");
+
+        assert_eq!(
+            body(request),
+            json!({
+                "commands": [
+                    {"command": "start", "description": "Start the bot"},
+                    {"command": "help", "description": "Show help"},
+                ],
+                "scope": expected,
+                "language_code": "en",
+            })
+        );
+    }
+
+    assert_eq!(body(SetMyCommands::new(vec![])), json!({"commands": []}));
+}
+
+#[test]
+fn get_my_commands_response() {
+    let mut request = GetMyCommands::new();
+    request.scope(BotCommandScope::AllGroupChats);
+    assert_eq!(body(request), json!({"scope": {"type": "all_group_chats"}}));
+
+    let commands = response::<GetMyCommands>(json!([
+        {"command": "start", "description": "Start the bot"},
+    ]));
+    assert_eq!(commands, vec![BotCommand::new("start", "Start the bot")]);
+}
+
+#[test]
+fn copy_message() {
+    let message: Message = serde_json::from_value(json!({
+        "message_id": 5,
+        "from": user_value(),
+        "date": 1625090000,
+        "chat": {"id": 42, "type": "private", "first_name": "Fedor"},
+        "photo": [{"file_id": "p", "file_unique_id": "u", "width": 1, "height": 1}],
+        "caption": "original"
+    }))
+    .unwrap();
+
+    let mut request = message.copy(ChatRef::ChannelUsername("@review".into()));
+    request
+        .caption("Reported photo")
+        .caption_entities(vec![MessageEntity::new(MessageEntityKind::Bold, 0, 8)])
+        .disable_notification()
+        .reply_to(MessageId::new(3))
+        .allow_sending_without_reply()
+        .reply_markup(InlineKeyboardMarkup::from(vec![vec![
+            InlineKeyboardButton::callback("Approve", "approve"),
+        ]]));
+
+    assert_eq!(
+        body(request),
+        json!({
+            "chat_id": "@review",
+            "from_chat_id": 42,
+            "message_id": 5,
+            "caption": "Reported photo",
+            "caption_entities": [{"type": "bold", "offset": 0, "length": 8}],
+            "disable_notification": true,
+            "reply_to_message_id": 3,
+            "allow_sending_without_reply": true,
+            "reply_markup": {
+                "inline_keyboard": [[{"text": "Approve", "callback_data": "approve"}]]
+            },
+        })
+    );
+
+    let mut request = message.copy(ChatId::new(-100));
+    request
+        .caption("<b>Reported</b>")
+        .parse_mode(ParseMode::Html);
+    assert_eq!(
+        body(request),
+        json!({
+            "chat_id": -100,
+            "from_chat_id": 42,
+            "message_id": 5,
+            "caption": "<b>Reported</b>",
+            "parse_mode": "HTML",
+        })
+    );
+}
+
+#[test]
+fn copy_message_response() {
+    let message_id = response::<CopyMessage>(json!({"message_id": 17}));
+    assert_eq!(message_id, MessageId::new(17));
+}
+
+#[test]
+fn send_invoice() {
+    let mut request = SendInvoice::new(
+        ChatId::new(42),
+        "Coffee",
+        "A cup of coffee",
+        "order-1",
+        "provider-token",
+        "EUR",
+        vec![LabeledPrice::new("Coffee", 250)],
+    );
+    request
+        .add_price(LabeledPrice::new("Tip", 50))
+        .provider_data(r#"{"receipt":true}"#)
+        .need_email()
+        .flexible();
+
+    assert_eq!(
+        body(request),
+        json!({
+            "chat_id": 42,
+            "title": "Coffee",
+            "description": "A cup of coffee",
+            "payload": "order-1",
+            "provider_token": "provider-token",
+            "currency": "EUR",
+            "prices": [
+                {"label": "Coffee", "amount": 250},
+                {"label": "Tip", "amount": 50},
+            ],
+            "provider_data": "{\"receipt\":true}",
+            "need_email": true,
+            "is_flexible": true,
+        })
+    );
+}
+
+#[test]
+fn chat_settings() {
+    let chat = ChatId::new(-100);
+    assert_eq!(
+        body(chat.set_title("Team")),
+        json!({"chat_id": -100, "title": "Team"})
+    );
+    assert_eq!(
+        body(chat.set_description("Our team chat")),
+        json!({"chat_id": -100, "description": "Our team chat"})
+    );
+    assert_eq!(body(chat.delete_photo()), json!({"chat_id": -100}));
+    assert_eq!(
+        body(chat.set_sticker_set("team_stickers")),
+        json!({"chat_id": -100, "sticker_set_name": "team_stickers"})
+    );
+    assert_eq!(body(chat.delete_sticker_set()), json!({"chat_id": -100}));
+    assert_eq!(body(chat.unpin_all_messages()), json!({"chat_id": -100}));
+}
+
+#[test]
+fn set_chat_photo() {
+    let photo = InputFileUpload::with_data(&b"jpeg"[..], "photo.jpg");
+    let request = ChatId::new(-100).set_photo(photo).serialize().unwrap();
+    let multipart = match request.body {
+        Body::Multipart(multipart) => multipart,
+        body => panic!("unexpected body: {:?}", body),
+    };
+
+    assert_eq!(
+        multipart.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+        vec!["chat_id", "photo"]
+    );
+    assert_eq!(multipart[0].1, MultipartValue::Text("-100".into()));
+    assert_eq!(
+        multipart[1].1,
+        MultipartValue::Data {
+            file_name: "photo.jpg".into(),
+            data: b"jpeg"[..].into(),
+        }
+    );
+}
+
+#[test]
+fn invite_links() {
+    let chat = ChatId::new(-100);
+
+    let mut create = chat.create_invite_link();
+    create
+        .name("Friends")
+        .expire_date(1700000000)
+        .member_limit(10);
+    assert_eq!(
+        body(create),
+        json!({
+            "chat_id": -100,
+            "name": "Friends",
+            "expire_date": 1700000000,
+            "member_limit": 10,
+        })
+    );
+
+    let mut edit = chat.edit_invite_link("https://t.me/+abc");
+    edit.creates_join_request();
+    assert_eq!(
+        body(edit),
+        json!({
+            "chat_id": -100,
+            "invite_link": "https://t.me/+abc",
+            "creates_join_request": true,
+        })
+    );
+
+    assert_eq!(
+        body(chat.revoke_invite_link("https://t.me/+abc")),
+        json!({"chat_id": -100, "invite_link": "https://t.me/+abc"})
+    );
+}
+
+#[test]
+fn chat_join_requests() {
+    let expected = json!({"chat_id": -100, "user_id": 42});
+    assert_eq!(
+        body(ChatId::new(-100).approve_join_request(user())),
+        expected
+    );
+    assert_eq!(
+        body(user().approve_join_request_in(ChatId::new(-100))),
+        expected
+    );
+    assert_eq!(
+        body(ChatId::new(-100).decline_join_request(user())),
+        expected
+    );
+    assert_eq!(
+        body(user().decline_join_request_in(ChatId::new(-100))),
+        expected
+    );
+}
+
+#[test]
+fn chat_invite_link_response() {
+    let link = response::<CreateChatInviteLink>(json!({
+        "invite_link": "https://t.me/+abc",
+        "creator": {"id": 1, "is_bot": true, "first_name": "Bot"},
+        "creates_join_request": false,
+        "is_primary": false,
+        "is_revoked": false,
+        "name": "Friends",
+        "expire_date": 1700000000,
+        "member_limit": 10,
+    }));
+
+    assert_eq!(link.invite_link, "https://t.me/+abc");
+    assert_eq!(link.creator.id, UserId::new(1));
+    assert!(!link.creates_join_request && !link.is_primary && !link.is_revoked);
+    assert_eq!(link.name.as_deref(), Some("Friends"));
+    assert_eq!(link.expire_date, Some(1700000000));
+    assert_eq!(link.member_limit, Some(10));
+    assert_eq!(link.pending_join_request_count, None);
+}
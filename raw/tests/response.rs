@@ -0,0 +1,69 @@
+use std::fs::File;
+use std::io::prelude::*;
+
+use telegram_bot_raw::types::chat::Chat;
+use telegram_bot_raw::types::chat_full_info::ChatFullInfo;
+use telegram_bot_raw::types::message::{MessageKind, MessageOrChannelPost};
+use telegram_bot_raw::types::web_app::MenuButton;
+
+macro_rules! make_test {
+    ($asset: ident, $typ: ty, $test: expr) => {
+        #[test]
+        fn $asset() {
+            let data = {
+                let filename = format!("tests/response_assets/{}.json", stringify!($asset));
+                let mut data = Vec::new();
+                let mut file = File::open(filename).unwrap();
+                file.read_to_end(&mut data).unwrap();
+                data
+            };
+            let response = serde_json::from_slice::<$typ>(&data).unwrap();
+            $test(response)
+        }
+    };
+}
+
+make_test!(get_chat, ChatFullInfo, |info: ChatFullInfo| {
+    if let Chat::Supergroup(ref supergroup) = info.chat {
+        assert_eq!(supergroup.title, "Test supergroup");
+    } else {
+        panic!("unexpected chat kind")
+    }
+    assert_eq!(info.slow_mode_delay, Some(30));
+    assert_eq!(info.permissions.unwrap().can_send_polls, Some(false));
+    assert_eq!(info.location.unwrap().address, "Moscow");
+});
+
+make_test!(get_chat_channel, ChatFullInfo, |info: ChatFullInfo| {
+    if let Chat::Channel(ref channel) = info.chat {
+        assert_eq!(channel.title, "Test channel");
+    } else {
+        panic!("unexpected chat kind")
+    }
+    match info.pinned_message.map(|message| *message) {
+        Some(MessageOrChannelPost::ChannelPost(post)) => {
+            assert_eq!(post.chat.title, "Test channel");
+            if let MessageKind::Text { ref data, .. } = post.kind {
+                assert_eq!(data, "Welcome to the channel");
+            } else {
+                panic!("unexpected message kind")
+            }
+        }
+        message => panic!("unexpected pinned message: {:?}", message),
+    }
+});
+
+make_test!(get_chat_menu_button, MenuButton, |button: MenuButton| {
+    assert_eq!(
+        button,
+        MenuButton::web_app("Order", "https://example.com/shop")
+    );
+    assert_eq!(
+        serde_json::to_value(&button).unwrap(),
+        serde_json::json!({
+            "type": "web_app",
+            "text": "Order",
+            "web_app": {"url": "https://example.com/shop"}
+        })
+    );
+});
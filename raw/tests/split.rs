@@ -0,0 +1,158 @@
+use telegram_bot_raw::requests::{SendMessage, SendPhoto, SplitCaption};
+use telegram_bot_raw::types::message::{MessageEntity, MessageEntityKind::*};
+use telegram_bot_raw::types::refs::{ChatId, FileRef, MessageId};
+use telegram_bot_raw::types::reply_markup::ForceReply;
+use telegram_bot_raw::{split_caption, split_text, FormattedText, MarkupError, ParseMode};
+
+fn texts(parts: &[(String, Vec<MessageEntity>)]) -> Vec<&str> {
+    parts.iter().map(|(text, _)| text.as_str()).collect()
+}
+
+#[test]
+fn short_text_is_not_split() {
+    let parts = split_text("Hello", &[], 10);
+    assert_eq!(texts(&parts), vec!["Hello"]);
+}
+
+#[test]
+fn prefers_paragraphs_then_lines_then_words() {
+    let parts = split_text("aaa bbb\nccc\n\nddd", &[], 13);
+    assert_eq!(texts(&parts), vec!["aaa bbb\nccc", "ddd"]);
+
+    let parts = split_text("aaa bbb\nccc ddd", &[], 12);
+    assert_eq!(texts(&parts), vec!["aaa bbb", "ccc ddd"]);
+
+    let parts = split_text("aaa bbb ccc", &[], 8);
+    assert_eq!(texts(&parts), vec!["aaa bbb", "ccc"]);
+
+    let parts = split_text("aaaaaaaaaa", &[], 4);
+    assert_eq!(texts(&parts), vec!["aaaa", "aaaa", "aa"]);
+}
+
+#[test]
+fn limit_is_in_utf16_code_units() {
+    let parts = split_text("😀😀😀", &[], 4);
+    assert_eq!(texts(&parts), vec!["😀😀", "😀"]);
+
+    let parts = split_text("😀😀😀", &[], 3);
+    assert_eq!(texts(&parts), vec!["😀", "😀", "😀"]);
+}
+
+#[test]
+fn entities_are_cut_and_rebased() {
+    let mut text = FormattedText::new();
+    text.text("one ").bold("two three").text(" four");
+
+    let parts = text.split(9);
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[0].as_str(), "one two");
+    assert_eq!(parts[0].entities(), &[MessageEntity::new(Bold, 4, 3)]);
+    assert_eq!(parts[1].as_str(), "three");
+    assert_eq!(parts[1].entities(), &[MessageEntity::new(Bold, 0, 5)]);
+    assert_eq!(parts[2].as_str(), "four");
+    assert!(parts[2].entities().is_empty());
+    assert_eq!(parts[1].to_html(), "<b>three</b>");
+}
+
+#[test]
+fn links_are_not_broken_if_possible() {
+    let mut text = FormattedText::new();
+    text.text("see ").link("the docs", "https://example.com");
+
+    let parts = text.split(10);
+    assert_eq!(parts[0].as_str(), "see");
+    assert_eq!(parts[1].as_str(), "the docs");
+    assert_eq!(
+        parts[1].to_html(),
+        "<a href=\"https://example.com\">the docs</a>"
+    );
+}
+
+#[test]
+fn send_message_split() {
+    let text = format!("{}\n\n{}", "a".repeat(4000), "b".repeat(200));
+    let mut request = SendMessage::new(ChatId::new(1), "");
+    request
+        .formatted_text(text)
+        .reply_to(MessageId::new(2))
+        .reply_markup(ForceReply::new());
+
+    let parts = request.clone().split().unwrap();
+    assert_eq!(parts.len(), 2);
+
+    let mut first = SendMessage::new(ChatId::new(1), "a".repeat(4000));
+    first.entities(Vec::new()).reply_to(MessageId::new(2));
+    let mut second = SendMessage::new(ChatId::new(1), "b".repeat(200));
+    second.entities(Vec::new()).reply_markup(ForceReply::new());
+    assert_eq!(parts, vec![first, second]);
+
+    let mut request = SendMessage::new(ChatId::new(1), "c".repeat(5000));
+    request.parse_mode(ParseMode::Html);
+    assert_eq!(request.clone().split().unwrap().len(), 2);
+}
+
+#[test]
+fn send_message_split_markup() {
+    let markup = format!(
+        "<b>{}</b>\n\n<i>{} &amp; b</i>",
+        "a".repeat(4000),
+        "b".repeat(200)
+    );
+    let mut request = SendMessage::new(ChatId::new(1), markup);
+    request.parse_mode(ParseMode::Html);
+
+    let mut first = SendMessage::new(ChatId::new(1), "a".repeat(4000));
+    first.entities(vec![MessageEntity::new(Bold, 0, 4000)]);
+    let mut second = SendMessage::new(ChatId::new(1), format!("{} & b", "b".repeat(200)));
+    second.entities(vec![MessageEntity::new(Italic, 0, 204)]);
+    assert_eq!(request.split(), Ok(vec![first, second]));
+
+    let mut request = SendMessage::new(ChatId::new(1), format!("<b>{}", "a".repeat(5000)));
+    request.parse_mode(ParseMode::Html);
+    assert_eq!(request.split(), Err(MarkupError::Unclosed("b".into())));
+}
+
+#[test]
+fn send_message_split_never_empty() {
+    let request = SendMessage::new(ChatId::new(1), "");
+    assert_eq!(request.clone().split(), Ok(vec![request]));
+
+    let mut request = SendMessage::new(ChatId::new(1), "short");
+    request.parse_mode(ParseMode::MarkdownV2);
+    assert_eq!(request.clone().split(), Ok(vec![request]));
+}
+
+#[test]
+fn caption_split() {
+    let text = format!("{} {}", "a".repeat(1000), "b".repeat(100));
+    let ((caption, _), rest) = split_caption(&text, &[]);
+    assert_eq!(caption, "a".repeat(1000));
+    assert_eq!(texts(&rest), vec!["b".repeat(100)]);
+
+    let mut request = SendPhoto::new(ChatId::new(1), FileRef::from("photo"));
+    request.caption(text).reply_markup(ForceReply::new());
+    let (photo, rest) = request.split_caption().unwrap();
+
+    let mut expected = SendPhoto::new(ChatId::new(1), FileRef::from("photo"));
+    expected
+        .caption("a".repeat(1000))
+        .reply_markup(ForceReply::new());
+    assert_eq!(photo, expected);
+    assert_eq!(
+        rest,
+        vec![SendMessage::new(ChatId::new(1), "b".repeat(100))]
+    );
+
+    let mut request = SendPhoto::new(ChatId::new(1), FileRef::from("photo"));
+    request.caption("short");
+    assert_eq!(request.clone().split_caption(), Ok((request, Vec::new())));
+
+    let mut request = SendPhoto::new(ChatId::new(1), FileRef::from("photo"));
+    request
+        .caption("*unclosed")
+        .parse_mode(ParseMode::MarkdownV2);
+    assert_eq!(
+        request.split_caption(),
+        Err(MarkupError::Unclosed("*".into()))
+    );
+}
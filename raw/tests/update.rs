@@ -1,7 +1,10 @@
 use std::fs::File;
 use std::io::prelude::*;
 
-use telegram_bot_raw::types::message::MessageKind;
+use telegram_bot_raw::types::chat_member::ChatMemberStatus;
+use telegram_bot_raw::types::message::{DiceEmoji, MessageEntityKind, MessageKind};
+use telegram_bot_raw::types::passport::PassportElementType;
+use telegram_bot_raw::types::reply_markup::{InlineKeyboardButton, InlineKeyboardButtonKind};
 use telegram_bot_raw::types::update::{Update, UpdateKind};
 
 macro_rules! make_test {
@@ -54,3 +57,246 @@ make_test!(regression_test_208, |update: Update| {
 
     assert!(false)
 });
+
+make_test!(pre_checkout_query, |update: Update| {
+    if let UpdateKind::PreCheckoutQuery(query) = update.kind {
+        assert_eq!(query.total_amount, 145);
+        return;
+    }
+
+    panic!("unexpected update kind")
+});
+
+make_test!(successful_payment, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::SuccessfulPayment { data } = message.kind {
+            assert_eq!(data.invoice_payload, "order-42");
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(game, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::Game { data } = message.kind {
+            assert_eq!(data.title, "Lumberjack");
+            assert_eq!(data.photo.len(), 1);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(chat_member_restricted, |update: Update| {
+    if let UpdateKind::ChatMember(update) = update.kind {
+        assert_eq!(update.new_chat_member.status, ChatMemberStatus::Restricted);
+        assert_eq!(update.new_chat_member.until_date, Some(1625086600));
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(chat_join_request, |update: Update| {
+    if let UpdateKind::ChatJoinRequest(request) = update.kind {
+        let invite_link = request.invite_link.unwrap();
+        assert!(invite_link.creates_join_request);
+        assert_eq!(invite_link.name.as_deref(), Some("Landing page"));
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(sender_chat, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        assert!(message.sender_chat.is_some());
+        assert_eq!(message.author_signature.as_deref(), Some("Moderator"));
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(via_bot, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        assert_eq!(message.via_bot.unwrap().username.as_deref(), Some("gif"));
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(caption_entities, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::Photo {
+            caption_entities, ..
+        } = message.kind
+        {
+            assert_eq!(caption_entities.len(), 1);
+            assert_eq!(caption_entities[0].kind, MessageEntityKind::Bold);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(reply_markup, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        let reply_markup = message.reply_markup.unwrap();
+        assert_eq!(reply_markup.inline_keyboard()[0].len(), 2);
+        assert_eq!(reply_markup.inline_keyboard()[0][0].text(), "Yes");
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(reply_markup_unknown_button, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        let reply_markup = message.reply_markup.unwrap();
+        let keyboard = reply_markup.inline_keyboard();
+        assert_eq!(
+            keyboard[0][0].kind(),
+            &InlineKeyboardButtonKind::Unknown(
+                serde_json::from_value(serde_json::json!({"copy_text": {"text": "promo-2024"}}))
+                    .unwrap()
+            )
+        );
+        assert_eq!(keyboard[0][1].text(), "Share");
+        assert_eq!(
+            keyboard[1][0].kind(),
+            &InlineKeyboardButtonKind::CallbackData("yes".into())
+        );
+
+        let copy = serde_json::to_value(&keyboard[0][0]).unwrap();
+        assert_eq!(
+            copy,
+            serde_json::json!({"text": "Copy", "copy_text": {"text": "promo-2024"}})
+        );
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+#[test]
+fn reply_markup_malformed_known_button() {
+    let button = serde_json::json!({"text": "Open", "url": 5});
+    assert!(serde_json::from_value::<InlineKeyboardButton>(button).is_err());
+}
+
+make_test!(has_protected_content, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        assert!(message.has_protected_content);
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(forward_signature, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        assert_eq!(
+            message.forward.unwrap().signature.as_deref(),
+            Some("Editor")
+        );
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(connected_website, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        assert_eq!(message.connected_website.as_deref(), Some("example.com"));
+        return;
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(proximity_alert_triggered, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::ProximityAlertTriggered { data } = message.kind {
+            assert_eq!(data.distance, 150);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(message_auto_delete_timer_changed, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::MessageAutoDeleteTimerChanged { data } = message.kind {
+            assert_eq!(data.message_auto_delete_time, 86400);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(video_chat_scheduled, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::VideoChatScheduled { data } = message.kind {
+            assert_eq!(data.start_date, 1625090000);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(video_chat_started, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::VideoChatStarted = message.kind {
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(video_chat_ended, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::VideoChatEnded { data } = message.kind {
+            assert_eq!(data.duration, 3600);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(voice_chat_participants_invited, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::VideoChatParticipantsInvited { data } = message.kind {
+            assert_eq!(data.users.len(), 1);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(dice, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::Dice { data } = message.kind {
+            assert_eq!(data.emoji, DiceEmoji::Dice);
+            assert_eq!(data.value, 4);
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(web_app_data, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::WebAppData { data } = message.kind {
+            assert_eq!(data.data, r#"{"size":"XL"}"#);
+            assert_eq!(data.button_text, "Choose size");
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});
+
+make_test!(passport_data, |update: Update| {
+    if let UpdateKind::Message(message) = update.kind {
+        if let MessageKind::PassportData { data } = message.kind {
+            assert_eq!(data.data.len(), 3);
+            assert_eq!(data.data[0].kind, PassportElementType::PersonalDetails);
+            assert!(data.data[1].front_side.is_some());
+            return;
+        }
+    }
+    panic!("unexpected update kind")
+});